@@ -0,0 +1,50 @@
+//! Stream a request with a 64 MiB body to JSON without buffering it.
+//!
+//! ```sh
+//! cargo run --release --example stream_json
+//! ```
+
+use std::io::{self, Read, Write};
+
+use wireframe::{ParserConfig, stream_json};
+
+const BODY_LEN: u64 = 64 * 1024 * 1024;
+
+/// Counts the bytes written and keeps only the first few for display.
+struct Summary {
+    written: u64,
+    preview: Vec<u8>,
+}
+
+impl Write for Summary {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let room = 160usize.saturating_sub(self.preview.len());
+        self.preview.extend_from_slice(&buf[..room.min(buf.len())]);
+        self.written += buf.len() as u64;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn main() -> io::Result<()> {
+    let head =
+        format!("PUT /upload HTTP/1.1\r\nHost: example.com\r\nContent-Length: {BODY_LEN}\r\n\r\n");
+    let request = head.as_bytes().chain(io::repeat(b'x').take(BODY_LEN));
+
+    let config = ParserConfig {
+        max_body_size: usize::MAX,
+        ..ParserConfig::default()
+    };
+    let mut out = Summary {
+        written: 0,
+        preview: Vec::new(),
+    };
+    stream_json(request, &mut out, config)?;
+
+    println!("{}...", String::from_utf8_lossy(&out.preview));
+    println!("{} bytes of JSON for a {BODY_LEN}-byte body", out.written);
+    Ok(())
+}
@@ -0,0 +1,34 @@
+//! Print the parser's `tracing` events for a few requests.
+//!
+//! ```sh
+//! cargo run --example tracing_events --features tracing
+//! ```
+
+use wireframe::{ParseStatus, Parser};
+
+fn main() {
+    tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::TRACE)
+        .with_target(false)
+        .init();
+
+    let inputs: &[&[u8]] = &[
+        b"GET / HTTP/1.1\r\nHost: example.com\r\n\r\n",
+        b"POST /upload HTTP/1.1\r\nHost: h\r\nTransfer-Encoding: chunked\r\n\r\n5\r\nHello\r\n0\r\n\r\n",
+        b"POST / HTTP/1.1\r\nHost: h\r\nContent-Length: nope\r\n\r\n",
+    ];
+
+    for input in inputs {
+        let mut parser = Parser::new();
+        match parser.feed(input) {
+            Ok(ParseStatus::Complete(_)) => {
+                let request = parser.finish().expect("complete request");
+                println!("parsed: {request}");
+            }
+            Ok(ParseStatus::Incomplete | ParseStatus::AwaitingContinue(_)) => {
+                println!("incomplete")
+            }
+            Err(e) => println!("rejected: {e}"),
+        }
+    }
+}
@@ -1,11 +1,12 @@
 use std::io::{IsTerminal, Read};
 use std::path::PathBuf;
 use std::process;
+use std::time::{Duration, Instant};
 
 use clap::{CommandFactory, Parser as ClapParser};
 
 use wireframe::{
-    ParserConfig, format_debug, format_headers_only, format_json, parse_request_with_config,
+    ParseError, ParseStatus, Parser, ParserConfig, format_debug, format_headers_only, format_json,
 };
 
 /// WireFrame CLI — strict HTTP/1.1 request parser.
@@ -42,6 +43,34 @@ struct Cli {
     /// Maximum number of headers allowed.
     #[arg(long, default_value = "128")]
     max_headers: usize,
+
+    /// Print to stderr how many bytes follow the parsed request.
+    #[arg(long)]
+    report_leftover: bool,
+
+    /// Fail (exit code 3) if any bytes follow the parsed request.
+    #[arg(long)]
+    require_exact: bool,
+
+    /// Rewrite LF-only line endings in the request line and headers to CRLF
+    /// before parsing (the body is left untouched).
+    #[arg(long)]
+    auto_crlf: bool,
+
+    /// Parse imperfect input: use the tolerant parser preset and imply
+    /// --auto-crlf.
+    #[arg(long)]
+    tolerant: bool,
+
+    /// Parse the input N times and print timing statistics instead of the
+    /// request.
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    bench: Option<u32>,
+
+    /// Print the parser state that handled each input byte, as a table,
+    /// instead of the request.
+    #[arg(long)]
+    trace: bool,
 }
 
 #[derive(clap::ValueEnum, Clone, Debug)]
@@ -78,13 +107,74 @@ fn main() {
         process::exit(1);
     }
 
+    let auto_crlf = cli.auto_crlf || cli.tolerant;
+    let data = match auto_crlf.then(|| lf_to_crlf(&data)).flatten() {
+        Some((converted, count)) => {
+            eprintln!("Note: converted {count} LF line ending(s) to CRLF");
+            converted
+        }
+        None => data,
+    };
+
+    let base = if cli.tolerant {
+        ParserConfig::tolerant()
+    } else {
+        ParserConfig::default()
+    };
     let config = ParserConfig {
         max_body_size: cli.max_body_size,
         max_headers_count: cli.max_headers,
-        ..ParserConfig::default()
+        ..base
     };
 
-    let request = match parse_request_with_config(&data, config) {
+    if let Some(iterations) = cli.bench {
+        match bench(&data, &config, iterations) {
+            Ok(timings) => print!("{}", format_timings(&timings, data.len())),
+            Err(e) => {
+                eprintln!("Parse error: {e}");
+                process::exit(2);
+            }
+        }
+        return;
+    }
+
+    if cli.trace {
+        let trace = Parser::with_config(config.clone()).trace_states(&data);
+        print!("{}", format_trace(&trace));
+        // The trace ends at the first error; a fresh parser reports it.
+        if let Err(e) = Parser::with_config(config).feed(&data) {
+            eprintln!("Parse error: {e}");
+            process::exit(2);
+        }
+        return;
+    }
+
+    let mut parser = Parser::with_config(config);
+    let consumed = match parser.feed(&data) {
+        Ok(ParseStatus::Complete(n)) => n,
+        // The tolerant preset accepts a chunked body cut off right before
+        // its final CRLF; `finish` decides.
+        Ok(ParseStatus::Incomplete) if cli.tolerant => data.len(),
+        Ok(ParseStatus::Incomplete | ParseStatus::AwaitingContinue(_)) => {
+            eprintln!("Parse error: {}", ParseError::IncompleteRequest);
+            process::exit(2);
+        }
+        Err(e) => {
+            eprintln!("Parse error: {e}");
+            process::exit(2);
+        }
+    };
+
+    let leftover = data.len() - consumed;
+    if cli.report_leftover {
+        eprintln!("{leftover} trailing byte(s) after the request");
+    }
+    if cli.require_exact && leftover > 0 {
+        eprintln!("Error: {leftover} trailing byte(s) after the request");
+        process::exit(3);
+    }
+
+    let request = match parser.finish() {
         Ok(r) => r,
         Err(e) => {
             eprintln!("Parse error: {e}");
@@ -101,6 +191,61 @@ fn main() {
     print!("{output}");
 }
 
+/// Parse `data` `iterations` times with a fresh parser each time, returning
+/// the duration of every run. Input is already in memory, so no I/O is
+/// measured.
+fn bench(data: &[u8], config: &ParserConfig, iterations: u32) -> Result<Vec<Duration>, ParseError> {
+    (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            let mut parser = Parser::with_config(config.clone());
+            match parser.feed(data)? {
+                ParseStatus::Complete(_) => {}
+                ParseStatus::Incomplete | ParseStatus::AwaitingContinue(_) => {
+                    return Err(ParseError::IncompleteRequest);
+                }
+            }
+            std::hint::black_box(parser.finish()?);
+            Ok(start.elapsed())
+        })
+        .collect()
+}
+
+/// Summarise benchmark timings as min/median/max/mean and throughput.
+fn format_timings(timings: &[Duration], input_len: usize) -> String {
+    let mut sorted = timings.to_vec();
+    sorted.sort();
+    let total: Duration = sorted.iter().sum();
+    let mean = total / sorted.len() as u32;
+    let median = sorted[sorted.len() / 2];
+    let mib_s =
+        (input_len * sorted.len()) as f64 / total.as_secs_f64().max(f64::EPSILON) / 1_048_576.0;
+    format!(
+        "iterations: {}\ninput: {input_len} bytes\nmin: {:?}\nmedian: {median:?}\nmax: {:?}\nmean: {mean:?}\nthroughput: {mib_s:.1} MiB/s\n",
+        sorted.len(),
+        sorted[0],
+        sorted[sorted.len() - 1],
+    )
+}
+
+/// Lay out a state trace as an `offset  byte  state` table, with SP and
+/// control bytes spelled out.
+fn format_trace(trace: &[(usize, char, &str)]) -> String {
+    let mut out = String::from("offset  byte  state\n");
+    for &(offset, byte, state) in trace {
+        let byte = match byte {
+            ' ' => "SP".to_string(),
+            '\t' => "HTAB".to_string(),
+            '\r' => "CR".to_string(),
+            '\n' => "LF".to_string(),
+            c if c.is_ascii_graphic() => c.to_string(),
+            c => format!("0x{:02X}", u32::from(c)),
+        };
+        out.push_str(&format!("{offset:>6}  {byte:<4}  {state}\n"));
+    }
+    out
+}
+
 /// Read raw HTTP bytes from --raw, a file, or stdin.
 fn read_input(cli: &Cli) -> Result<Vec<u8>, std::io::Error> {
     if let Some(raw) = &cli.raw {
@@ -116,6 +261,39 @@ fn read_input(cli: &Cli) -> Result<Vec<u8>, std::io::Error> {
     }
 }
 
+/// Rewrite the bare LF line endings of the request line and header section
+/// to CRLF, returning the new input and the number of endings changed.
+///
+/// The header section ends at the first blank line after the request line;
+/// everything after it is body and copied verbatim. Returns `None` when
+/// every line ending there is already CRLF.
+fn lf_to_crlf(data: &[u8]) -> Option<(Vec<u8>, usize)> {
+    let mut out = Vec::with_capacity(data.len() + 64);
+    let mut count = 0;
+    let mut pos = 0;
+    let mut seen_request_line = false;
+    while let Some(offset) = data[pos..].iter().position(|&b| b == b'\n') {
+        let line = &data[pos..pos + offset];
+        let content = match line.strip_suffix(b"\r") {
+            Some(content) => content,
+            None => {
+                count += 1;
+                line
+            }
+        };
+        out.extend_from_slice(content);
+        out.extend_from_slice(b"\r\n");
+        pos += offset + 1;
+        if !content.is_empty() {
+            seen_request_line = true;
+        } else if seen_request_line {
+            break;
+        }
+    }
+    out.extend_from_slice(&data[pos..]);
+    (count > 0).then_some((out, count))
+}
+
 /// Expand C-style escape sequences (`\r`, `\n`, `\t`, `\\`) in a string.
 ///
 /// Any other `\X` sequence is kept as-is (both the backslash and `X`).
@@ -0,0 +1,255 @@
+//! Decoders for common request body formats.
+//!
+//! These operate on the already-buffered [`HttpRequest::body`] and are
+//! selected by the request's `Content-Type`.
+
+use crate::error::ParseError;
+use crate::headers::{parse_media_type, parse_parameters};
+use crate::parser::{is_field_content_byte, is_tchar};
+use crate::types::{Header, HttpRequest};
+
+// ---------------------------------------------------------------------------
+// multipart/form-data
+// ---------------------------------------------------------------------------
+
+/// One part of a `multipart/form-data` body (RFC 7578).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultipartPart {
+    /// The part's header fields, in order.
+    pub headers: Vec<Header>,
+    /// The part's content.
+    pub body: Vec<u8>,
+}
+
+impl MultipartPart {
+    /// Look up the first part header value by name (case-insensitive).
+    pub fn header_value(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|h| h.name.eq_ignore_ascii_case(name))
+            .map(|h| h.value.as_str())
+    }
+
+    /// The form field name from `Content-Disposition: form-data; name="..."`.
+    pub fn content_disposition_name(&self) -> Option<String> {
+        self.disposition_param("name")
+    }
+
+    /// The `filename` parameter of `Content-Disposition`, present for file
+    /// uploads.
+    pub fn filename(&self) -> Option<String> {
+        self.disposition_param("filename")
+    }
+
+    fn disposition_param(&self, param: &str) -> Option<String> {
+        let value = self.header_value("content-disposition")?;
+        let (_, params) = value.split_once(';')?;
+        parse_parameters(params)
+            .into_iter()
+            .find(|(name, _)| name == param)
+            .map(|(_, v)| v)
+    }
+}
+
+/// Split a multipart body on `boundary` into its parts.
+fn split_multipart(body: &[u8], boundary: &str) -> Result<Vec<MultipartPart>, ParseError> {
+    let delimiter = format!("--{boundary}").into_bytes();
+    let next_delimiter = [b"\r\n".as_slice(), &delimiter].concat();
+
+    // The first delimiter may be preceded by a preamble, which is ignored.
+    let mut pos = if body.starts_with(&delimiter) {
+        delimiter.len()
+    } else {
+        find(body, &next_delimiter).ok_or(ParseError::InvalidMultipart)? + next_delimiter.len()
+    };
+
+    let mut parts = Vec::new();
+    loop {
+        let rest = &body[pos..];
+        if rest.starts_with(b"--") {
+            // Close delimiter; any epilogue is ignored.
+            return Ok(parts);
+        }
+
+        // Optional transport padding, then the CRLF ending the delimiter line.
+        let padding = rest
+            .iter()
+            .take_while(|&&b| b == b' ' || b == b'\t')
+            .count();
+        if !rest[padding..].starts_with(b"\r\n") {
+            return Err(ParseError::InvalidMultipart);
+        }
+        pos += padding + 2;
+
+        let end = pos + find(&body[pos..], &next_delimiter).ok_or(ParseError::InvalidMultipart)?;
+        parts.push(parse_part(&body[pos..end])?);
+        pos = end + next_delimiter.len();
+    }
+}
+
+/// Parse one part: header lines, an empty line, then content.
+///
+/// Part headers follow the request header grammar: CRLF-terminated lines,
+/// a token name directly followed by `:`, and a value of field-content
+/// bytes with only SP/HTAB trimmed around it.
+fn parse_part(part: &[u8]) -> Result<MultipartPart, ParseError> {
+    let (head, body) = if let Some(body) = part.strip_prefix(b"\r\n") {
+        (&[][..], body)
+    } else {
+        let end = find(part, b"\r\n\r\n").ok_or(ParseError::InvalidMultipart)?;
+        (&part[..end], &part[end + 4..])
+    };
+
+    let mut headers = Vec::new();
+    let mut rest = head;
+    while !rest.is_empty() {
+        let line = match find(rest, b"\r\n") {
+            Some(end) => {
+                let line = &rest[..end];
+                rest = &rest[end + 2..];
+                line
+            }
+            None => std::mem::take(&mut rest),
+        };
+        let colon = line
+            .iter()
+            .position(|&b| b == b':')
+            .ok_or(ParseError::InvalidMultipart)?;
+        let (name, value) = (&line[..colon], trim_ows(&line[colon + 1..]));
+        if name.is_empty()
+            || !name.iter().all(|&b| is_tchar(b))
+            || !value.iter().all(|&b| is_field_content_byte(b))
+        {
+            return Err(ParseError::InvalidMultipart);
+        }
+        headers.push(Header {
+            name: String::from_utf8_lossy(name).into_owned(),
+            value: String::from_utf8_lossy(value).into_owned(),
+            raw_value: None,
+            value_bytes: None,
+        });
+    }
+
+    Ok(MultipartPart {
+        headers,
+        body: body.to_vec(),
+    })
+}
+
+/// `value` without leading and trailing SP/HTAB.
+fn trim_ows(value: &[u8]) -> &[u8] {
+    let is_ows = |b: &u8| *b == b' ' || *b == b'\t';
+    let start = value.iter().position(|b| !is_ows(b)).unwrap_or(value.len());
+    let end = value
+        .iter()
+        .rposition(|b| !is_ows(b))
+        .map_or(start, |i| i + 1);
+    &value[start..end]
+}
+
+/// Position of the first occurrence of `needle` in `haystack`.
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+// ---------------------------------------------------------------------------
+// application/x-www-form-urlencoded
+// ---------------------------------------------------------------------------
+
+/// Percent-decode `input`, optionally treating `+` as a space.
+///
+/// Malformed escapes (`%` not followed by two hex digits) are kept
+/// verbatim. Decoded bytes that are not valid UTF-8 are replaced lossily.
+pub(crate) fn percent_decode(input: &[u8], plus_as_space: bool) -> String {
+    let mut out = Vec::with_capacity(input.len());
+    let mut i = 0;
+    while i < input.len() {
+        match input[i] {
+            b'%' => match (input.get(i + 1), input.get(i + 2)) {
+                (Some(&hi), Some(&lo)) if hi.is_ascii_hexdigit() && lo.is_ascii_hexdigit() => {
+                    out.push(hex_value(hi) << 4 | hex_value(lo));
+                    i += 3;
+                }
+                _ => {
+                    out.push(b'%');
+                    i += 1;
+                }
+            },
+            b'+' if plus_as_space => {
+                out.push(b' ');
+                i += 1;
+            }
+            b => {
+                out.push(b);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+fn hex_value(digit: u8) -> u8 {
+    match digit {
+        b'0'..=b'9' => digit - b'0',
+        b'a'..=b'f' => digit - b'a' + 10,
+        _ => digit - b'A' + 10,
+    }
+}
+
+/// Split a form-urlencoded string into decoded `(key, value)` pairs.
+fn parse_form_urlencoded(input: &[u8]) -> Vec<(String, String)> {
+    input
+        .split(|&b| b == b'&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = match pair.iter().position(|&b| b == b'=') {
+                Some(eq) => (&pair[..eq], &pair[eq + 1..]),
+                None => (pair, &[][..]),
+            };
+            (percent_decode(key, true), percent_decode(value, true))
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// HttpRequest accessors
+// ---------------------------------------------------------------------------
+
+impl HttpRequest {
+    /// Split a `multipart/form-data` body into its parts.
+    ///
+    /// Returns `None` unless `Content-Type` is `multipart/form-data`. A
+    /// missing `boundary` parameter, a missing body, or a body that does not
+    /// follow the multipart syntax yields `Some(Err(ParseError::InvalidMultipart))`.
+    pub fn multipart_parts(&self) -> Option<Result<Vec<MultipartPart>, ParseError>> {
+        let (media_type, params) = parse_media_type(self.header_value("content-type")?)?;
+        if media_type != "multipart/form-data" {
+            return None;
+        }
+
+        let boundary = params
+            .into_iter()
+            .find(|(name, _)| name == "boundary")
+            .map(|(_, v)| v)
+            .filter(|b| !b.is_empty() && b.len() <= 70);
+
+        Some(match (boundary, self.body_bytes()) {
+            (Some(boundary), Some(body)) => split_multipart(body, &boundary),
+            _ => Err(ParseError::InvalidMultipart),
+        })
+    }
+
+    /// Decode an `application/x-www-form-urlencoded` body into key/value
+    /// pairs, in order.
+    ///
+    /// `+` decodes to a space and `%XX` escapes are decoded in both keys and
+    /// values. Returns `None` if the content type does not match or there is
+    /// no body.
+    pub fn form_params(&self) -> Option<Vec<(String, String)>> {
+        let (media_type, _) = parse_media_type(self.header_value("content-type")?)?;
+        if media_type != "application/x-www-form-urlencoded" {
+            return None;
+        }
+        self.body_bytes().map(parse_form_urlencoded)
+    }
+}
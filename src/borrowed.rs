@@ -0,0 +1,332 @@
+//! Zero-copy request view.
+//!
+//! [`parse_request_ref`] validates a complete request with the same scanner
+//! as [`request_boundary`](crate::request_boundary) and then hands out
+//! slices of the caller's buffer instead of copying them. Only a chunked
+//! body of several chunks, which is not contiguous on the wire, is decoded
+//! into an owned buffer. [`BorrowingParser`] does the same for a request
+//! fed in several slices.
+
+use std::borrow::Cow;
+use std::ops::Range;
+
+use crate::error::ParseError;
+use crate::parser::{ParseStatus, ParserConfig};
+use crate::scan::{ScanProgress, resume_boundary};
+use crate::types::{Header, HttpMethod, HttpRequest, HttpVersion};
+
+/// A header field borrowed from the input buffer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeaderRef<'a> {
+    /// Header field name (original casing preserved).
+    pub name: Cow<'a, str>,
+    /// Header field value (leading/trailing OWS trimmed).
+    pub value: Cow<'a, str>,
+    /// The exact value bytes when they are not valid UTF-8; see
+    /// [`Header::value_bytes`].
+    pub value_bytes: Option<Cow<'a, [u8]>>,
+}
+
+impl HeaderRef<'_> {
+    fn into_owned(self) -> Header {
+        Header {
+            name: self.name.into_owned(),
+            value: self.value.into_owned(),
+            raw_value: None,
+            value_bytes: self.value_bytes.map(Cow::into_owned),
+        }
+    }
+}
+
+/// A parsed HTTP request that borrows from the input buffer.
+///
+/// Text fields are `Cow::Borrowed` whenever the input is valid UTF-8; see
+/// [`HttpRequestRef::to_owned`] to keep a request past the buffer's
+/// lifetime.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequestRef<'a> {
+    /// The request method.
+    pub method: HttpMethod,
+    /// The request target (URI / path).
+    pub uri: Cow<'a, str>,
+    /// The HTTP version.
+    pub version: HttpVersion,
+    /// The list of header fields.
+    pub headers: Vec<HeaderRef<'a>>,
+    /// The trailer fields that followed a chunked body, in order.
+    pub trailers: Vec<HeaderRef<'a>>,
+    /// See [`HttpRequest::chunk_lengths`].
+    pub chunk_lengths: Vec<usize>,
+    /// The optional request body (owned only for chunked bodies).
+    pub body: Option<Cow<'a, [u8]>>,
+    /// Number of bytes the request occupied on the wire.
+    pub received_bytes: usize,
+}
+
+impl HttpRequestRef<'_> {
+    /// Copy every field into an owned [`HttpRequest`].
+    ///
+    /// The result is identical to parsing the same bytes with
+    /// [`parse_request`](crate::parse_request).
+    pub fn to_owned(&self) -> HttpRequest {
+        self.clone().into_owned()
+    }
+
+    /// Convert into an owned [`HttpRequest`], reusing any buffers that are
+    /// already owned.
+    pub fn into_owned(self) -> HttpRequest {
+        HttpRequest {
+            method: self.method,
+            uri: self.uri.into_owned(),
+            version: self.version,
+            headers: self
+                .headers
+                .into_iter()
+                .map(HeaderRef::into_owned)
+                .collect(),
+            trailers: self
+                .trailers
+                .into_iter()
+                .map(HeaderRef::into_owned)
+                .collect(),
+            chunk_lengths: self.chunk_lengths,
+            body: self.body.map(Cow::into_owned),
+            received_bytes: self.received_bytes,
+            post_terminator_data: false,
+            raw_bytes: None,
+            parsed_with: None,
+        }
+    }
+}
+
+/// Parse a **complete** HTTP request without copying its fields.
+///
+/// Accepts the same input as [`parse_request`](crate::parse_request) with
+/// the default [`ParserConfig`]; any bytes after the first request are
+/// ignored.
+///
+/// # Errors
+///
+/// Returns [`ParseError`] if the data is malformed or incomplete.
+pub fn parse_request_ref(data: &[u8]) -> Result<HttpRequestRef<'_>, ParseError> {
+    let config = ParserConfig::default();
+    let mut spans = None;
+    let len = resume_boundary(
+        data,
+        0,
+        &config,
+        &mut ScanProgress::default(),
+        Some(&mut spans),
+    )?
+    .ok_or(ParseError::IncompleteRequest)?;
+    let mut spans = spans.ok_or(ParseError::IncompleteRequest)?;
+    spans.len = len;
+    Ok(spans.assemble(|range| Cow::Borrowed(&data[range])))
+}
+
+// ---------------------------------------------------------------------------
+// BorrowingParser
+// ---------------------------------------------------------------------------
+
+/// An incremental parser whose result borrows from the fed slices.
+///
+/// Where [`Parser`](crate::Parser) copies every field into owned buffers,
+/// `BorrowingParser` keeps the input slices themselves: the caller
+/// guarantees that every slice passed to [`feed`](Self::feed) outlives the
+/// parser, and [`finish`](Self::finish) returns an [`HttpRequestRef`] whose
+/// fields point into them. Only a field that straddles two slices (and a
+/// chunked body of more than one chunk) is copied.
+///
+/// Validation records where each field sits instead of copying it. The
+/// one other copy is the unfinished line or chunk-size at the end of a
+/// slice, with the whole head while it is still incomplete, which is kept
+/// in a reusable scratch buffer until the next slice completes it. Body
+/// data is never copied for validation.
+///
+/// ```rust
+/// use wireframe::{BorrowingParser, ParseStatus};
+///
+/// let (a, b) = (b"GET /index HTTP/1.1\r\nHo".as_slice(), b"st: h\r\n\r\n".as_slice());
+/// let mut parser = BorrowingParser::new();
+/// assert_eq!(parser.feed(a).unwrap(), ParseStatus::Incomplete);
+/// assert!(matches!(parser.feed(b).unwrap(), ParseStatus::Complete(_)));
+/// let request = parser.finish().unwrap();
+/// assert_eq!(request.uri, "/index");
+/// ```
+pub struct BorrowingParser<'a> {
+    config: ParserConfig,
+    segments: Vec<&'a [u8]>,
+    /// Offset of the first byte not yet validated, or of `scratch`.
+    window_start: usize,
+    /// Unvalidated tail of the previous slices.
+    scratch: Vec<u8>,
+    progress: ScanProgress,
+    spans: Option<Spans>,
+    request_len: Option<usize>,
+}
+
+impl<'a> BorrowingParser<'a> {
+    /// Create a new parser with default configuration.
+    pub fn new() -> Self {
+        Self::with_config(ParserConfig::default())
+    }
+
+    /// Create a new parser with custom limits.
+    pub fn with_config(config: ParserConfig) -> Self {
+        Self {
+            config,
+            segments: Vec::new(),
+            window_start: 0,
+            scratch: Vec::new(),
+            progress: ScanProgress::default(),
+            spans: None,
+            request_len: None,
+        }
+    }
+
+    /// Feed the next slice of input, which must stay alive as long as the
+    /// parser and its result.
+    ///
+    /// Returns [`ParseStatus::Complete`] with the total number of request
+    /// bytes once a full request has been seen; input fed after that is
+    /// ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError`] on any protocol violation or limit breach.
+    pub fn feed(&mut self, data: &'a [u8]) -> Result<ParseStatus, ParseError> {
+        if let Some(len) = self.request_len {
+            return Ok(ParseStatus::Complete(len));
+        }
+
+        self.segments.push(data);
+        let carried = !self.scratch.is_empty();
+        if carried {
+            self.scratch.extend_from_slice(data);
+        }
+        let window = if carried { &self.scratch[..] } else { data };
+
+        let boundary = resume_boundary(
+            window,
+            self.window_start,
+            &self.config,
+            &mut self.progress,
+            Some(&mut self.spans),
+        )?;
+        if let Some(len) = boundary {
+            let len = self.window_start + len;
+            self.request_len = Some(len);
+            return Ok(ParseStatus::Complete(len));
+        }
+
+        // Carry only what the scanner has not validated yet.
+        let keep = self.progress.resume_at().min(window.len());
+        self.progress.rebase(keep);
+        self.window_start += keep;
+        if carried {
+            self.scratch.drain(..keep);
+        } else {
+            self.scratch.extend_from_slice(&data[keep..]);
+        }
+        Ok(ParseStatus::Incomplete)
+    }
+
+    /// Return the parsed request, borrowing from the fed slices.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::IncompleteRequest`] if no complete request has
+    /// been fed yet.
+    pub fn finish(self) -> Result<HttpRequestRef<'a>, ParseError> {
+        let len = self.request_len.ok_or(ParseError::IncompleteRequest)?;
+        let mut spans = self.spans.ok_or(ParseError::IncompleteRequest)?;
+        spans.len = len;
+
+        let segments = &self.segments;
+        Ok(spans.assemble(|range| {
+            let mut owned = Vec::new();
+            let mut offset = 0;
+            for &segment in segments {
+                let end = offset + segment.len();
+                if range.start >= offset && range.end <= end {
+                    return Cow::Borrowed(&segment[range.start - offset..range.end - offset]);
+                }
+                if range.start < end && range.end > offset {
+                    // The field straddles this slice and the next.
+                    let from = range.start.max(offset) - offset;
+                    let to = range.end.min(end) - offset;
+                    owned.extend_from_slice(&segment[from..to]);
+                }
+                offset = end;
+            }
+            Cow::Owned(owned)
+        }))
+    }
+}
+
+impl Default for BorrowingParser<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Field spans
+// ---------------------------------------------------------------------------
+
+/// Byte ranges of every field of a validated request, relative to its
+/// first byte, as recorded by the scanner.
+pub(crate) struct Spans {
+    pub(crate) method: HttpMethod,
+    pub(crate) uri: Range<usize>,
+    pub(crate) version: HttpVersion,
+    pub(crate) headers: Vec<(Range<usize>, Range<usize>)>,
+    pub(crate) trailers: Vec<(Range<usize>, Range<usize>)>,
+    /// Body data: one range for `Content-Length`, one per chunk otherwise.
+    pub(crate) body: Vec<Range<usize>>,
+    /// Chunk sizes, with `record_chunk_boundaries`.
+    pub(crate) chunk_lengths: Vec<usize>,
+    pub(crate) len: usize,
+}
+
+impl Spans {
+    /// Build the request view, resolving each range through `slice`.
+    fn assemble<'a>(self, slice: impl Fn(Range<usize>) -> Cow<'a, [u8]>) -> HttpRequestRef<'a> {
+        let decode = |bytes: &Cow<'a, [u8]>| match bytes {
+            Cow::Borrowed(bytes) => String::from_utf8_lossy(bytes),
+            Cow::Owned(bytes) => Cow::Owned(String::from_utf8_lossy(bytes).into_owned()),
+        };
+        let text = |range| decode(&slice(range));
+
+        let body = match self.body.as_slice() {
+            [] => None,
+            [single] => Some(slice(single.clone())),
+            chunks => Some(Cow::Owned(
+                chunks
+                    .iter()
+                    .flat_map(|c| slice(c.clone()).into_owned())
+                    .collect(),
+            )),
+        };
+
+        let field = |(name, value)| {
+            let bytes = slice(value);
+            HeaderRef {
+                name: text(name),
+                value: decode(&bytes),
+                value_bytes: std::str::from_utf8(&bytes).is_err().then_some(bytes),
+            }
+        };
+
+        HttpRequestRef {
+            method: self.method,
+            uri: text(self.uri),
+            version: self.version,
+            headers: self.headers.into_iter().map(field).collect(),
+            trailers: self.trailers.into_iter().map(field).collect(),
+            chunk_lengths: self.chunk_lengths,
+            body,
+            received_bytes: self.len,
+        }
+    }
+}
@@ -0,0 +1,67 @@
+//! Protocol sniffing for multiplexed listeners.
+//!
+//! [`detect_protocol`] classifies the first bytes of a connection so a
+//! front door can hand it to the right stack before committing to
+//! [`Parser`](crate::Parser).
+
+use crate::parser::is_tchar;
+
+/// The HTTP/2 client connection preface up to the end of its fake request
+/// line and empty line (RFC 9113 §3.4).
+const HTTP2_PREFACE: &[u8] = b"PRI * HTTP/2.0\r\n\r\n";
+
+/// TLS record content type of a handshake, the first byte of a
+/// `ClientHello` (RFC 8446 §5.1).
+const TLS_HANDSHAKE: u8 = 0x16;
+
+/// What the leading bytes of a connection look like.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProtocolHint {
+    /// Starts like an HTTP/1.x request line: a method token followed by SP
+    /// (leading empty lines skipped).
+    Http1,
+    /// Starts with the HTTP/2 connection preface `PRI * HTTP/2.0\r\n\r\n`.
+    Http2Preface,
+    /// Starts with a TLS handshake record.
+    Tls,
+    /// Anything else, including input too short to tell apart (e.g. a
+    /// prefix of the HTTP/2 preface).
+    Unknown,
+}
+
+/// Classify the first bytes of a connection without consuming them.
+///
+/// The check is a cheap heuristic on a peeked prefix; a few bytes are
+/// usually enough, and the full HTTP/2 preface is only 18 bytes. A
+/// [`ProtocolHint::Http1`] answer means "worth handing to the parser", not
+/// that the request is valid.
+///
+/// ```rust
+/// use wireframe::{ProtocolHint, detect_protocol};
+///
+/// assert_eq!(detect_protocol(b"GET / HTTP/1.1\r\n"), ProtocolHint::Http1);
+/// assert_eq!(detect_protocol(&[0x16, 0x03, 0x01]), ProtocolHint::Tls);
+/// ```
+pub fn detect_protocol(data: &[u8]) -> ProtocolHint {
+    if data.starts_with(HTTP2_PREFACE) {
+        return ProtocolHint::Http2Preface;
+    }
+    if HTTP2_PREFACE.starts_with(data) {
+        // Empty, or could still turn out to be the preface.
+        return ProtocolHint::Unknown;
+    }
+    if data[0] == TLS_HANDSHAKE {
+        return ProtocolHint::Tls;
+    }
+
+    let mut line = data;
+    while let Some(rest) = line.strip_prefix(b"\r\n") {
+        line = rest;
+    }
+    let method_len = line.iter().take_while(|&&b| is_tchar(b)).count();
+    if method_len > 0 && matches!(line.get(method_len), Some(b' ') | None) {
+        ProtocolHint::Http1
+    } else {
+        ProtocolHint::Unknown
+    }
+}
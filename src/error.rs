@@ -1,7 +1,11 @@
 use std::fmt;
 
 /// Errors that can occur during HTTP request parsing.
+///
+/// This enum is `#[non_exhaustive]`: new variants may be added in minor
+/// releases, so downstream `match` expressions need a wildcard arm.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum ParseError {
     /// The HTTP method is not a recognized standard method.
     InvalidMethod(String),
@@ -9,10 +13,24 @@ pub enum ParseError {
     InvalidVersion(String),
     /// The request URI is malformed or empty.
     InvalidUri(String),
+    /// The request line uses `HTTP/2.0` (e.g. the h2 prior-knowledge
+    /// connection preface). Only reported with `ParserConfig::detect_http2`.
+    Http2PrefaceDetected,
+    /// The request line contains whitespace other than the single SP
+    /// separators required by RFC 9112 §3.
+    InvalidRequestLine(String),
     /// The `Content-Length` header value is not a valid integer.
     InvalidContentLength(String),
+    /// A header value is not a valid HTTP-date (RFC 9110 §5.6.7).
+    InvalidDate(String),
     /// A chunk size in chunked transfer encoding is not valid hexadecimal.
     InvalidChunkSize(String),
+    /// The `Transfer-Encoding` header names a coding the parser is not
+    /// configured to accept.
+    UnsupportedTransferCoding(String),
+    /// The `Transfer-Encoding` coding list cannot frame a request body
+    /// (`chunked` missing, repeated, or not final).
+    InvalidTransferEncoding(String),
     /// An unexpected byte was encountered during parsing.
     UnexpectedByte {
         /// Human-readable description of what was expected.
@@ -20,14 +38,74 @@ pub enum ParseError {
         /// The actual byte value found.
         found: u8,
     },
+    /// A header value contains a CR that is not followed by LF — a common
+    /// CR-injection pattern.
+    BareCr,
     /// A header name or value exceeds the configured maximum size.
+    #[deprecated(
+        note = "split into `HeaderNameTooLarge` and `HeaderValueTooLarge`; no longer returned"
+    )]
     HeaderTooLarge,
+    /// More than `max_leading_ows` spaces or tabs preceded a header value
+    /// (carries the header name).
+    ExcessiveWhitespace(String),
+    /// A header name exceeds `max_header_name_len` (carries the partial name).
+    HeaderNameTooLarge(String),
+    /// A header value exceeds `max_header_value_len` (carries the header name).
+    HeaderValueTooLarge(String),
     /// The request body exceeds the configured maximum size.
     BodyTooLarge,
+    /// The whole request exceeds `max_total_request_size`.
+    RequestTooLarge,
     /// The number of headers exceeds the configured maximum.
     TooManyHeaders,
+    /// The URI path has more segments than `max_uri_path_segments`.
+    TooManyPathSegments,
+    /// The chunked trailer section exceeds the configured maximum size.
+    TrailerTooLarge,
+    /// A chunk extension is longer than `max_chunk_ext_len`.
+    ChunkExtensionTooLarge,
+    /// Too many bytes were consumed without the parser changing state.
+    NoProgress,
+    /// A `multipart/form-data` body is malformed or lacks a boundary.
+    InvalidMultipart,
+    /// The `Content-Range` header is not a valid `unit first-last/length`.
+    InvalidContentRange,
+    /// [`Parser::set_body_framing`](crate::Parser::set_body_framing) was
+    /// called before the headers were complete or after the body started.
+    BodyFramingLocked,
+    /// A header line starts with `:`, i.e. has an empty field name.
+    EmptyHeaderName,
+    /// Whitespace between a header name and its colon (`Host : h`), which
+    /// RFC 9112 §5.1 forbids (with `reject_space_before_colon`). Carries
+    /// the name.
+    WhitespaceBeforeColon(String),
+    /// An HTTP/1.1 request has no `Host` header (with `require_host`).
+    MissingHost,
+    /// A `Host` header disagrees with the authority of an absolute-form
+    /// target (with `validate_host_matches_absolute`).
+    HostAuthorityMismatch {
+        /// The `Host` header value.
+        host: String,
+        /// The `host[:port]` of the request target.
+        authority: String,
+    },
+    /// A header listed in `forbidden_header_names` was received.
+    ForbiddenHeader(String),
+    /// Bytes followed a chunked body's terminator (with
+    /// `strict_chunked_termination`).
+    DataAfterChunkedBody,
+    /// A URI or header contains CR, LF or NUL (see
+    /// [`HttpRequest::validate_no_injection`](crate::HttpRequest::validate_no_injection)).
+    UnsafeFieldContent(String),
+    /// Bytes remained after the request (from
+    /// [`parse_request_exact`](crate::parse_request_exact)); holds their
+    /// count.
+    TrailingData(usize),
     /// The request data ended before a complete HTTP request was parsed.
     IncompleteRequest,
+    /// Reading request data from an I/O source failed.
+    Io(std::io::ErrorKind),
 }
 
 impl fmt::Display for ParseError {
@@ -36,17 +114,84 @@ impl fmt::Display for ParseError {
             Self::InvalidMethod(m) => write!(f, "invalid HTTP method: '{m}'"),
             Self::InvalidVersion(v) => write!(f, "invalid HTTP version: '{v}'"),
             Self::InvalidUri(u) => write!(f, "invalid request URI: '{u}'"),
+            Self::Http2PrefaceDetected => write!(f, "HTTP/2 connection preface detected"),
+            Self::InvalidRequestLine(r) => write!(f, "invalid request line: {r}"),
             Self::InvalidContentLength(v) => write!(f, "invalid Content-Length: '{v}'"),
+            Self::InvalidDate(d) => write!(f, "invalid HTTP-date: '{d}'"),
             Self::InvalidChunkSize(s) => write!(f, "invalid chunk size: '{s}'"),
+            Self::UnsupportedTransferCoding(c) => {
+                write!(f, "unsupported transfer coding: '{c}'")
+            }
+            Self::InvalidTransferEncoding(v) => write!(f, "invalid Transfer-Encoding: '{v}'"),
             Self::UnexpectedByte { expected, found } => {
                 write!(f, "unexpected byte 0x{found:02X} (expected {expected})")
             }
+            Self::BareCr => write!(f, "bare CR in header value"),
+            #[allow(deprecated)]
             Self::HeaderTooLarge => write!(f, "header exceeds maximum allowed size"),
+            Self::ExcessiveWhitespace(name) => {
+                write!(f, "too much whitespace before the value of header '{name}'")
+            }
+            Self::HeaderNameTooLarge(name) => {
+                write!(f, "header name exceeds maximum allowed size: {name}...")
+            }
+            Self::HeaderValueTooLarge(name) => {
+                write!(f, "value of header '{name}' exceeds maximum allowed size")
+            }
             Self::BodyTooLarge => write!(f, "body exceeds maximum allowed size"),
+            Self::RequestTooLarge => write!(f, "request exceeds maximum allowed total size"),
             Self::TooManyHeaders => write!(f, "number of headers exceeds maximum"),
+            Self::TooManyPathSegments => write!(f, "URI path has too many segments"),
+            Self::TrailerTooLarge => write!(f, "trailer section exceeds maximum allowed size"),
+            Self::ChunkExtensionTooLarge => {
+                write!(f, "chunk extension exceeds maximum allowed size")
+            }
+            Self::NoProgress => write!(f, "too many bytes without parser progress"),
+            Self::InvalidMultipart => write!(f, "malformed multipart body"),
+            Self::InvalidContentRange => write!(f, "malformed Content-Range header"),
+            Self::BodyFramingLocked => write!(
+                f,
+                "body framing can only be set between the header section and the body"
+            ),
+            Self::EmptyHeaderName => write!(f, "header line with an empty field name"),
+            Self::WhitespaceBeforeColon(name) => {
+                write!(f, "whitespace between header name '{name}' and ':'")
+            }
+            Self::MissingHost => write!(f, "HTTP/1.1 request without a Host header"),
+            Self::HostAuthorityMismatch { host, authority } => write!(
+                f,
+                "Host header '{host}' does not match request target authority '{authority}'"
+            ),
+            Self::ForbiddenHeader(name) => write!(f, "forbidden header: '{name}'"),
+            Self::DataAfterChunkedBody => {
+                write!(f, "unexpected data after the end of a chunked body")
+            }
+            Self::UnsafeFieldContent(field) => {
+                write!(f, "{field} contains CR, LF or NUL")
+            }
+            Self::TrailingData(n) => write!(f, "{n} trailing byte(s) after the request"),
             Self::IncompleteRequest => write!(f, "incomplete HTTP request"),
+            Self::Io(kind) => write!(f, "I/O error: {kind}"),
         }
     }
 }
 
 impl std::error::Error for ParseError {}
+
+/// Lets parsing compose with `?` in functions returning [`std::io::Result`].
+///
+/// [`ParseError::IncompleteRequest`] becomes
+/// [`UnexpectedEof`](std::io::ErrorKind::UnexpectedEof),
+/// [`ParseError::Io`] keeps its kind, and everything else is
+/// [`InvalidData`](std::io::ErrorKind::InvalidData) carrying the `Display`
+/// message.
+impl From<ParseError> for std::io::Error {
+    fn from(err: ParseError) -> Self {
+        let kind = match err {
+            ParseError::IncompleteRequest => std::io::ErrorKind::UnexpectedEof,
+            ParseError::Io(kind) => kind,
+            _ => std::io::ErrorKind::InvalidData,
+        };
+        Self::new(kind, err.to_string())
+    }
+}
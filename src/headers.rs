@@ -0,0 +1,569 @@
+//! Typed accessors for individual request header fields.
+//!
+//! These helpers work on an already-parsed [`HttpRequest`] and never
+//! affect how the state machine frames a request.
+
+use crate::error::ParseError;
+use crate::parser::is_tchar;
+use crate::types::{HttpMethod, HttpRequest};
+
+// ---------------------------------------------------------------------------
+// Entity tags
+// ---------------------------------------------------------------------------
+
+/// An entity-tag from a conditional request header (RFC 9110 §8.8.3).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ETag {
+    /// `true` for weak validators (`W/"..."`).
+    pub weak: bool,
+    /// The opaque tag without its surrounding quotes. The `*` wildcard is
+    /// represented as an unquoted strong tag with value `*`.
+    pub value: String,
+}
+
+impl ETag {
+    /// Return `true` if this is the `*` wildcard ("any current representation").
+    pub fn is_any(&self) -> bool {
+        !self.weak && self.value == "*"
+    }
+}
+
+/// Parse a comma-separated entity-tag list (`If-None-Match`, `If-Match`).
+///
+/// Malformed elements are skipped; a bare `*` yields a single wildcard tag.
+fn parse_etag_list(value: &str) -> Vec<ETag> {
+    let value = value.trim();
+    if value == "*" {
+        return vec![ETag {
+            weak: false,
+            value: "*".into(),
+        }];
+    }
+
+    let mut tags = Vec::new();
+    let mut rest = value;
+    loop {
+        rest = rest.trim_start_matches([' ', '\t', ',']);
+        if rest.is_empty() {
+            break;
+        }
+
+        let (weak, tail) = match rest.strip_prefix("W/") {
+            Some(t) => (true, t),
+            None => (false, rest),
+        };
+
+        // Commas are legal etagc bytes, so scan for the closing quote
+        // rather than splitting on ','.
+        let parsed = tail
+            .strip_prefix('"')
+            .and_then(|t| t.find('"').map(|end| (&t[..end], &t[end + 1..])));
+
+        match parsed {
+            Some((tag, after)) => {
+                tags.push(ETag {
+                    weak,
+                    value: tag.to_string(),
+                });
+                rest = after;
+            }
+            None => {
+                // Skip the malformed element.
+                rest = rest.find(',').map_or("", |i| &rest[i + 1..]);
+            }
+        }
+    }
+    tags
+}
+
+// ---------------------------------------------------------------------------
+// HTTP-date
+// ---------------------------------------------------------------------------
+
+const SHORT_DAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+const LONG_DAYS: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Parse an HTTP-date (RFC 9110 §5.6.7) into seconds since the Unix epoch.
+///
+/// Accepts the preferred IMF-fixdate (`Sun, 06 Nov 1994 08:49:37 GMT`) as
+/// well as the obsolete RFC 850 (`Sunday, 06-Nov-94 08:49:37 GMT`) and
+/// asctime (`Sun Nov  6 08:49:37 1994`) forms. Two-digit RFC 850 years
+/// below 70 are read as 20xx, the rest as 19xx.
+///
+/// # Errors
+///
+/// Returns [`ParseError::InvalidDate`] if the value matches none of the
+/// three formats or names an impossible date or time.
+pub fn parse_http_date(value: &str) -> Result<i64, ParseError> {
+    let value = value.trim();
+    let invalid = || ParseError::InvalidDate(value.to_string());
+
+    let (year, month, day, time) = if let Some((day_name, rest)) = value.split_once(", ") {
+        if SHORT_DAYS.contains(&day_name) {
+            // IMF-fixdate: "06 Nov 1994 08:49:37 GMT"
+            let parts: Vec<&str> = rest.split(' ').collect();
+            let [day, month, year, time, "GMT"] = parts[..] else {
+                return Err(invalid());
+            };
+            if day.len() != 2 || year.len() != 4 {
+                return Err(invalid());
+            }
+            (parse_num(year), month, parse_num(day), time)
+        } else if LONG_DAYS.contains(&day_name) {
+            // RFC 850: "06-Nov-94 08:49:37 GMT"
+            let parts: Vec<&str> = rest.split(' ').collect();
+            let [date, time, "GMT"] = parts[..] else {
+                return Err(invalid());
+            };
+            let date: Vec<&str> = date.split('-').collect();
+            let [day, month, yy] = date[..] else {
+                return Err(invalid());
+            };
+            if day.len() != 2 || yy.len() != 2 {
+                return Err(invalid());
+            }
+            let year = parse_num(yy).map(|y| if y < 70 { 2000 + y } else { 1900 + y });
+            (year, month, parse_num(day), time)
+        } else {
+            return Err(invalid());
+        }
+    } else {
+        // asctime: "Sun Nov  6 08:49:37 1994"
+        let parts: Vec<&str> = value.split_whitespace().collect();
+        let [day_name, month, day, time, year] = parts[..] else {
+            return Err(invalid());
+        };
+        if !SHORT_DAYS.contains(&day_name) || day.len() > 2 || year.len() != 4 {
+            return Err(invalid());
+        }
+        (parse_num(year), month, parse_num(day), time)
+    };
+
+    let month = MONTHS
+        .iter()
+        .position(|&m| m == month)
+        .ok_or_else(invalid)? as u32
+        + 1;
+    let (year, day) = (year.ok_or_else(invalid)?, day.ok_or_else(invalid)?);
+
+    let clock: Vec<Option<u32>> = time
+        .split(':')
+        .map(|t| if t.len() == 2 { parse_num(t) } else { None })
+        .collect();
+    let [Some(hour), Some(minute), Some(second)] = clock[..] else {
+        return Err(invalid());
+    };
+
+    if day == 0 || day > days_in_month(year, month) || hour > 23 || minute > 59 || second > 60 {
+        return Err(invalid());
+    }
+
+    let days = days_from_civil(year as i64, month, day);
+    Ok(days * 86_400 + i64::from(hour * 3_600 + minute * 60 + second))
+}
+
+/// Parse an all-ASCII-digit string.
+fn parse_num(s: &str) -> Option<u32> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400) => {
+            29
+        }
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let m = i64::from(month);
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+// ---------------------------------------------------------------------------
+// Media types and parameters
+// ---------------------------------------------------------------------------
+
+/// Split a `type/subtype; name=value; ...` field value into the lowercased
+/// media type and its parameters.
+///
+/// Returns `None` if the value does not start with a `type/subtype` pair.
+pub(crate) fn parse_media_type(value: &str) -> Option<(String, Vec<(String, String)>)> {
+    let (essence, params) = value.split_once(';').unwrap_or((value, ""));
+    let essence = essence.trim();
+    let (ty, subtype) = essence.split_once('/')?;
+    if ty.is_empty() || subtype.is_empty() {
+        return None;
+    }
+    Some((essence.to_ascii_lowercase(), parse_parameters(params)))
+}
+
+/// Parse `name=value` pairs separated by `;` (RFC 9110 §5.6.6).
+///
+/// Names are lowercased; quoted-string values are unquoted and unescaped.
+/// Elements without `=` are skipped.
+pub(crate) fn parse_parameters(s: &str) -> Vec<(String, String)> {
+    split_pairs(s, ';')
+        .into_iter()
+        .filter_map(|(name, value)| Some((name, value?)))
+        .collect()
+}
+
+/// Split `name[=value]` elements separated by `sep`, lowercasing names and
+/// unquoting quoted-string values. Empty names are dropped.
+fn split_pairs(s: &str, sep: char) -> Vec<(String, Option<String>)> {
+    let mut pairs = Vec::new();
+    let mut rest = s;
+    loop {
+        rest = rest.trim_start_matches([' ', '\t', sep]);
+        if rest.is_empty() {
+            return pairs;
+        }
+
+        let name_end = rest.find(['=', sep]).unwrap_or(rest.len());
+        let name = rest[..name_end].trim().to_ascii_lowercase();
+        rest = &rest[name_end..];
+
+        let value = rest.strip_prefix('=').map(|after_eq| {
+            let after_eq = after_eq.trim_start_matches([' ', '\t']);
+            if let Some(quoted) = after_eq.strip_prefix('"') {
+                let (value, consumed) = unquote(quoted);
+                rest = &quoted[consumed..];
+                value
+            } else {
+                let end = after_eq.find(sep).unwrap_or(after_eq.len());
+                rest = &after_eq[end..];
+                after_eq[..end].trim().to_string()
+            }
+        });
+
+        if !name.is_empty() {
+            pairs.push((name, value));
+        }
+    }
+}
+
+/// Decode the body of a quoted-string (after the opening quote).
+///
+/// Returns the unescaped text and the number of bytes consumed, including
+/// the closing quote when present.
+pub(crate) fn unquote(s: &str) -> (String, usize) {
+    let mut out = String::new();
+    let mut chars = s.char_indices();
+    while let Some((i, ch)) = chars.next() {
+        match ch {
+            '"' => return (out, i + 1),
+            '\\' => match chars.next() {
+                Some((_, escaped)) => out.push(escaped),
+                None => return (out, s.len()),
+            },
+            _ => out.push(ch),
+        }
+    }
+    (out, s.len())
+}
+
+// ---------------------------------------------------------------------------
+// Quality values
+// ---------------------------------------------------------------------------
+
+/// Parse a comma-separated list of `token[;param=value][;q=weight]`
+/// elements (RFC 9110 §12.4.2) into lowercased tokens and their weights.
+///
+/// A missing `q` means `1.0`; elements with a weight outside `0..=1` or
+/// that does not parse are skipped.
+fn parse_weighted_list(value: &str) -> Vec<(String, f32)> {
+    value
+        .split(',')
+        .filter_map(|element| {
+            let (token, params) = element.split_once(';').unwrap_or((element, ""));
+            let token = token.trim();
+            if token.is_empty() {
+                return None;
+            }
+            let weight = match parse_parameters(params)
+                .into_iter()
+                .find(|(name, _)| name == "q")
+            {
+                Some((_, q)) => q.parse::<f32>().ok().filter(|q| (0.0..=1.0).contains(q))?,
+                None => 1.0,
+            };
+            Some((token.to_ascii_lowercase(), weight))
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Cache-Control
+// ---------------------------------------------------------------------------
+
+/// Directives that keep a request from being answered from a cache.
+const UNCACHEABLE_DIRECTIVES: [&str; 2] = ["no-store", "no-cache"];
+
+// ---------------------------------------------------------------------------
+// Content-Range
+// ---------------------------------------------------------------------------
+
+/// A `Content-Range` field of a partial upload (RFC 9110 §14.4), e.g.
+/// `bytes 0-499/1234`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ContentRange {
+    /// The range unit, lowercased (almost always `bytes`).
+    pub unit: String,
+    /// Offset of the first byte in the range.
+    pub start: u64,
+    /// Offset of the last byte in the range (inclusive).
+    pub end: u64,
+    /// The complete length, or `None` when sent as `*` (unknown).
+    pub total: Option<u64>,
+}
+
+/// Parse `unit SP first-last/(length | *)`. The `*/length` form only
+/// appears in 416 responses and is rejected here.
+fn parse_content_range(value: &str) -> Result<ContentRange, ParseError> {
+    let digits = |s: &str| {
+        s.bytes()
+            .all(|b| b.is_ascii_digit())
+            .then(|| s.parse::<u64>().ok())
+            .flatten()
+    };
+    let parsed = value.trim().split_once(' ').and_then(|(unit, range)| {
+        let (range, total) = range.split_once('/')?;
+        let (start, end) = range.split_once('-')?;
+        let total = match total {
+            "*" => None,
+            total => Some(digits(total)?),
+        };
+        Some(ContentRange {
+            unit: unit.to_ascii_lowercase(),
+            start: digits(start)?,
+            end: digits(end)?,
+            total,
+        })
+    });
+    match parsed {
+        Some(range)
+            if !range.unit.is_empty()
+                && range.unit.bytes().all(is_tchar)
+                && range.start <= range.end
+                && range.total.is_none_or(|total| range.end < total) =>
+        {
+            Ok(range)
+        }
+        _ => Err(ParseError::InvalidContentRange),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Forwarded
+// ---------------------------------------------------------------------------
+
+/// Parse a `Forwarded` field value (RFC 7239 §4) into its elements.
+///
+/// Elements are separated by `,` and their pairs by `;`; both may appear
+/// inside quoted-string values (e.g. `for="[2001:db8::1]:4711"`), which are
+/// unquoted. Parameter names are lowercased; pairs without `=` are skipped.
+fn parse_forwarded(value: &str) -> Vec<Vec<(String, String)>> {
+    let mut elements = Vec::new();
+    let mut element = Vec::new();
+    let mut rest = value;
+    loop {
+        rest = rest.trim_start_matches([' ', '\t', ';']);
+        if let Some(after) = rest.strip_prefix(',') {
+            if !element.is_empty() {
+                elements.push(std::mem::take(&mut element));
+            }
+            rest = after;
+            continue;
+        }
+        if rest.is_empty() {
+            break;
+        }
+
+        let name_end = rest.find(['=', ';', ',']).unwrap_or(rest.len());
+        let name = rest[..name_end].trim().to_ascii_lowercase();
+        rest = &rest[name_end..];
+
+        let Some(after_eq) = rest.strip_prefix('=') else {
+            continue;
+        };
+        let after_eq = after_eq.trim_start_matches([' ', '\t']);
+        let value = if let Some(quoted) = after_eq.strip_prefix('"') {
+            let (value, consumed) = unquote(quoted);
+            rest = &quoted[consumed..];
+            value
+        } else {
+            let end = after_eq.find([';', ',']).unwrap_or(after_eq.len());
+            rest = &after_eq[end..];
+            after_eq[..end].trim().to_string()
+        };
+        if !name.is_empty() {
+            element.push((name, value));
+        }
+    }
+    if !element.is_empty() {
+        elements.push(element);
+    }
+    elements
+}
+
+// ---------------------------------------------------------------------------
+// HttpRequest accessors
+// ---------------------------------------------------------------------------
+
+impl HttpRequest {
+    /// Parse every `If-None-Match` field into its entity-tags, in order.
+    ///
+    /// Returns an empty vector when the header is absent.
+    pub fn if_none_match(&self) -> Vec<ETag> {
+        self.header_values("if-none-match")
+            .into_iter()
+            .flat_map(parse_etag_list)
+            .collect()
+    }
+
+    /// Parse the `If-Modified-Since` header as an HTTP-date, in seconds
+    /// since the Unix epoch.
+    ///
+    /// Returns `None` when the header is absent.
+    pub fn if_modified_since(&self) -> Option<Result<i64, ParseError>> {
+        self.header_value("if-modified-since").map(parse_http_date)
+    }
+
+    /// Parse every `Cache-Control` field into `(directive, argument)` pairs,
+    /// in order (RFC 9111 §5.2).
+    ///
+    /// Directive names are lowercased; quoted arguments such as
+    /// `no-cache="set-cookie, x-id"` are unquoted. Returns an empty vector
+    /// when the header is absent.
+    pub fn cache_control(&self) -> Vec<(String, Option<String>)> {
+        self.header_values("cache-control")
+            .into_iter()
+            .flat_map(|value| split_pairs(value, ','))
+            .collect()
+    }
+
+    /// The client chain from every `X-Forwarded-For` field, in order
+    /// (client first, then each proxy).
+    ///
+    /// Entries are trimmed and empty ones dropped; they are not validated
+    /// as IP addresses. Returns an empty vector when the header is absent.
+    pub fn forwarded_for(&self) -> Vec<String> {
+        self.header_values("x-forwarded-for")
+            .into_iter()
+            .flat_map(|value| value.split(','))
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Parse every `Forwarded` field (RFC 7239) into one list of
+    /// `(parameter, value)` pairs per hop, in order.
+    ///
+    /// Parameter names (`for`, `by`, `proto`, `host`, ...) are lowercased
+    /// and quoted values unquoted, so an IPv6 node such as
+    /// `for="[2001:db8::1]"` yields `[2001:db8::1]`. Returns an empty
+    /// vector when the header is absent.
+    pub fn forwarded(&self) -> Vec<Vec<(String, String)>> {
+        self.header_values("forwarded")
+            .into_iter()
+            .flat_map(parse_forwarded)
+            .collect()
+    }
+
+    /// The scheme the client used, as reported by a TLS-terminating proxy:
+    /// `"http"` or `"https"`, for building absolute redirect URLs.
+    ///
+    /// Checks the first entry of `X-Forwarded-Proto`, then the first
+    /// `proto` parameter of [`forwarded`](Self::forwarded) (the hop nearest
+    /// the client); values are compared case-insensitively and anything
+    /// other than `http` or `https` is ignored. Returns `None` when neither
+    /// header says. The headers are only as trustworthy as the proxies that
+    /// set them.
+    pub fn scheme_guess(&self) -> Option<&'static str> {
+        let known = |value: &str| {
+            let value = value.trim();
+            ["http", "https"]
+                .into_iter()
+                .find(|scheme| scheme.eq_ignore_ascii_case(value))
+        };
+        let forwarded_proto = self
+            .header_value("x-forwarded-proto")
+            .and_then(|value| value.split(',').next())
+            .and_then(known);
+        forwarded_proto.or_else(|| {
+            self.forwarded()
+                .into_iter()
+                .flatten()
+                .find(|(name, _)| name == "proto")
+                .and_then(|(_, value)| known(&value))
+        })
+    }
+
+    /// Parse every `TE` field into `(transfer coding, weight)` pairs, in
+    /// order (RFC 9110 §10.1.4).
+    ///
+    /// Codings are lowercased and weights default to `1.0`; the `trailers`
+    /// keyword appears as an entry too (see
+    /// [`accepts_trailers`](Self::accepts_trailers)). Returns an empty
+    /// vector when the header is absent.
+    pub fn te(&self) -> Vec<(String, f32)> {
+        self.header_values("te")
+            .into_iter()
+            .flat_map(parse_weighted_list)
+            .collect()
+    }
+
+    /// Whether `TE` lists `trailers`, i.e. the client is willing to accept
+    /// trailer fields in a chunked response.
+    pub fn accepts_trailers(&self) -> bool {
+        self.te().iter().any(|(coding, _)| coding == "trailers")
+    }
+
+    /// Parse the `Content-Range` header of a resumable upload (`PUT` or
+    /// `PATCH` carrying part of a representation).
+    ///
+    /// Returns `None` when the header is absent and
+    /// `Some(Err(ParseError::InvalidContentRange))` when it is malformed,
+    /// including an empty or reversed range or one ending past the total.
+    pub fn content_range(&self) -> Option<Result<ContentRange, ParseError>> {
+        self.header_value("content-range").map(parse_content_range)
+    }
+
+    /// A conservative guess at whether a shared cache may answer this
+    /// request: the method is `GET` or `HEAD`, there is no `Authorization`
+    /// header, and `Cache-Control` carries neither `no-store` nor
+    /// `no-cache`.
+    pub fn is_cacheable(&self) -> bool {
+        matches!(self.method, HttpMethod::GET | HttpMethod::HEAD)
+            && self.header_value("authorization").is_none()
+            && !self
+                .cache_control()
+                .iter()
+                .any(|(name, _)| UNCACHEABLE_DIRECTIVES.contains(&name.as_str()))
+    }
+}
@@ -35,31 +35,91 @@
 //! let request = parser.finish().unwrap();
 //! assert_eq!(request.uri, "/");
 //! ```
+//!
+//! ## Feature flags
+//!
+//! - `tracing` — emit [`tracing`](https://docs.rs/tracing) spans and events
+//!   from [`Parser::feed`] (header completion, framing decisions, errors with
+//!   their byte offset). Disabled by default; when off, no instrumentation
+//!   code is compiled in.
+//! - `futures` — `parse_request_async`, which reads one request from a
+//!   [`futures-io`](https://docs.rs/futures-io) `AsyncRead` source, for
+//!   `async-std` and other non-Tokio stacks.
 
+mod body;
+mod borrowed;
+mod detect;
 mod error;
+mod headers;
 mod output;
 mod parser;
+#[cfg(feature = "futures")]
+mod reader;
+mod scan;
+mod trace;
 mod types;
 
 // Re-export public API.
+pub use body::MultipartPart;
+pub use borrowed::{BorrowingParser, HeaderRef, HttpRequestRef, parse_request_ref};
+pub use detect::{ProtocolHint, detect_protocol};
 pub use error::ParseError;
-pub use output::{format_debug, format_headers_only, format_json};
-pub use parser::{ParseStatus, Parser, ParserConfig};
-pub use types::{Header, HttpMethod, HttpRequest, HttpVersion};
+pub use headers::{ContentRange, ETag, parse_http_date};
+pub use output::{
+    JsonOptions, format_debug, format_headers_only, format_json, format_json_with, stream_json,
+};
+pub use parser::{BodyChunkIter, HeaderAction, ParseStatus, Parser, ParserConfig, UriValidation};
+#[cfg(feature = "futures")]
+pub use reader::parse_request_async;
+pub use scan::request_boundary;
+pub use types::{BodyKind, Framing, Header, HttpMethod, HttpRequest, HttpVersion};
 
 /// Parse a **complete** HTTP request from a byte slice in one call.
 ///
 /// This is a convenience wrapper around [`Parser`]. For incremental /
 /// streaming use-cases, create a `Parser` directly.
 ///
+/// Any byte container works as input:
+///
+/// ```rust
+/// let raw = "GET / HTTP/1.1\r\nHost: h\r\n\r\n";
+/// let from_vec = wireframe::parse_request(raw.as_bytes().to_vec()).unwrap();
+/// let from_string = wireframe::parse_request(String::from(raw)).unwrap();
+/// assert_eq!(from_vec, from_string);
+/// ```
+///
 /// # Errors
 ///
 /// Returns [`ParseError`] if the data is malformed or incomplete.
-pub fn parse_request(data: &[u8]) -> Result<HttpRequest, ParseError> {
+pub fn parse_request(data: impl AsRef<[u8]>) -> Result<HttpRequest, ParseError> {
+    let mut parser = Parser::new();
+    match parser.feed(data.as_ref())? {
+        ParseStatus::Complete(_) => parser.finish(),
+        ParseStatus::Incomplete | ParseStatus::AwaitingContinue(_) => {
+            Err(ParseError::IncompleteRequest)
+        }
+    }
+}
+
+/// Parse `data` as **exactly one** complete HTTP request.
+///
+/// Unlike [`parse_request`], which ignores whatever follows the first
+/// request, this fails when any byte is left over — handy for validating
+/// fixtures and catching accidentally concatenated requests.
+///
+/// # Errors
+///
+/// Returns [`ParseError::TrailingData`] with the number of leftover bytes,
+/// or any error [`parse_request`] returns.
+pub fn parse_request_exact(data: impl AsRef<[u8]>) -> Result<HttpRequest, ParseError> {
+    let data = data.as_ref();
     let mut parser = Parser::new();
     match parser.feed(data)? {
+        ParseStatus::Complete(n) if n < data.len() => Err(ParseError::TrailingData(data.len() - n)),
         ParseStatus::Complete(_) => parser.finish(),
-        ParseStatus::Incomplete => Err(ParseError::IncompleteRequest),
+        ParseStatus::Incomplete | ParseStatus::AwaitingContinue(_) => {
+            Err(ParseError::IncompleteRequest)
+        }
     }
 }
 
@@ -70,12 +130,97 @@ pub fn parse_request(data: &[u8]) -> Result<HttpRequest, ParseError> {
 /// Returns [`ParseError`] if the data is malformed, incomplete, or
 /// exceeds the configured limits.
 pub fn parse_request_with_config(
-    data: &[u8],
+    data: impl AsRef<[u8]>,
     config: ParserConfig,
 ) -> Result<HttpRequest, ParseError> {
     let mut parser = Parser::with_config(config);
-    match parser.feed(data)? {
+    match parser.feed(data.as_ref())? {
         ParseStatus::Complete(_) => parser.finish(),
-        ParseStatus::Incomplete => Err(ParseError::IncompleteRequest),
+        ParseStatus::Incomplete | ParseStatus::AwaitingContinue(_) => {
+            Err(ParseError::IncompleteRequest)
+        }
     }
 }
+
+/// Parse only the request line and header section, leaving the body to
+/// the caller.
+///
+/// Returns the request (with `body: None`) and the offset of the first
+/// body byte in `data`, i.e. the length of the header section. The framing
+/// checks still run, so [`HttpRequest::framing`] tells the caller how to
+/// read the body that follows; nothing after the header section is
+/// examined or buffered.
+///
+/// ```rust
+/// let raw = b"POST / HTTP/1.1\r\nContent-Length: 5\r\n\r\nhello";
+/// let (request, body_start) =
+///     wireframe::parse_headers_only(raw, wireframe::ParserConfig::default()).unwrap();
+/// assert_eq!(request.framing(), wireframe::Framing::ContentLength(5));
+/// assert_eq!(&raw[body_start..], b"hello");
+/// ```
+///
+/// # Errors
+///
+/// Returns [`ParseError`] if the request line or headers are malformed,
+/// or [`ParseError::IncompleteRequest`] if `data` ends before the header
+/// section does.
+pub fn parse_headers_only(
+    data: impl AsRef<[u8]>,
+    config: ParserConfig,
+) -> Result<(HttpRequest, usize), ParseError> {
+    Parser::with_config(config).parse_head(data.as_ref())
+}
+
+/// Parse only the request line, e.g. for routing or logging.
+///
+/// Returns the method, target and version, and the offset just past the
+/// line's CRLF (empty lines before it included), where the header section
+/// starts. Nothing after the request line is examined.
+///
+/// ```rust
+/// let raw = b"GET /x HTTP/1.1\r\nHost: h\r\n\r\n";
+/// let (method, uri, version, end) = wireframe::parse_request_line(raw).unwrap();
+/// assert_eq!((method.as_str(), uri.as_str(), version.as_str()), ("GET", "/x", "HTTP/1.1"));
+/// assert_eq!(&raw[end..], b"Host: h\r\n\r\n");
+/// ```
+///
+/// # Errors
+///
+/// Returns [`ParseError`] if the request line is malformed, or
+/// [`ParseError::IncompleteRequest`] if `data` ends before it does.
+pub fn parse_request_line(
+    data: &[u8],
+) -> Result<(HttpMethod, String, HttpVersion, usize), ParseError> {
+    Parser::new().parse_line(data)
+}
+
+/// Parse a **complete** HTTP request held in a string.
+///
+/// Equivalent to [`parse_request`] on `s.as_ref().as_bytes()`; accepts
+/// `&str` and `String` alike.
+///
+/// ```rust
+/// let request = wireframe::parse_request_str("GET / HTTP/1.1\r\nHost: h\r\n\r\n").unwrap();
+/// assert_eq!(request.header_value("host"), Some("h"));
+/// ```
+///
+/// # Errors
+///
+/// Returns [`ParseError`] if the data is malformed or incomplete.
+pub fn parse_request_str(s: impl AsRef<str>) -> Result<HttpRequest, ParseError> {
+    parse_request(s.as_ref().as_bytes())
+}
+
+/// Parse a **complete** HTTP request held in a string using custom
+/// [`ParserConfig`] limits.
+///
+/// # Errors
+///
+/// Returns [`ParseError`] if the data is malformed, incomplete, or
+/// exceeds the configured limits.
+pub fn parse_request_str_with_config(
+    s: impl AsRef<str>,
+    config: ParserConfig,
+) -> Result<HttpRequest, ParseError> {
+    parse_request_with_config(s.as_ref().as_bytes(), config)
+}
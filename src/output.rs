@@ -1,3 +1,9 @@
+use std::io::{self, Read, Write};
+
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+use crate::error::ParseError;
+use crate::parser::{Parser, ParserConfig};
 use crate::types::HttpRequest;
 
 /// Serialize an [`HttpRequest`] to a JSON string.
@@ -11,6 +17,92 @@ pub fn format_json(request: &HttpRequest, pretty: bool) -> String {
     }
 }
 
+/// Options for [`format_json_with`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JsonOptions {
+    /// Indent the output for readability (default: `false`).
+    pub pretty: bool,
+    /// Omit `"body"` for requests without a body instead of writing
+    /// `null` (default: `false`).
+    pub skip_empty_body: bool,
+    /// Use descriptive field names: `request_method`, `request_uri`,
+    /// `http_version`, `request_headers`, `request_trailers` (only written
+    /// when there are trailers) and `request_body` (default: `false`, the
+    /// names of the [`HttpRequest`] fields).
+    pub rename_fields: bool,
+    /// Write [`HttpRequest::received_bytes`] as `"received_bytes"`
+    /// (default: `false`).
+    pub include_received_bytes: bool,
+    /// Write a non-empty [`HttpRequest::chunk_lengths`] as
+    /// `"chunk_lengths"` (default: `false`).
+    pub include_chunk_lengths: bool,
+    /// Write `"post_terminator_data": true` for requests with
+    /// [`HttpRequest::post_terminator_data`] set (default: `false`).
+    pub include_post_terminator_data: bool,
+}
+
+/// Serialize an [`HttpRequest`] to a JSON string, shaped by `options`.
+///
+/// With [`JsonOptions::default`] plus `pretty` this produces the same
+/// output as [`format_json`].
+pub fn format_json_with(request: &HttpRequest, options: &JsonOptions) -> String {
+    let view = JsonView { request, options };
+    let json = if options.pretty {
+        serde_json::to_string_pretty(&view)
+    } else {
+        serde_json::to_string(&view)
+    };
+    json.unwrap_or_else(|e| format!("{{\"error\": \"{e}\"}}"))
+}
+
+/// Serializes a request according to [`JsonOptions`].
+struct JsonView<'a> {
+    request: &'a HttpRequest,
+    options: &'a JsonOptions,
+}
+
+impl Serialize for JsonView<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let request = self.request;
+        let [method, uri, version, headers, trailers, body] = if self.options.rename_fields {
+            [
+                "request_method",
+                "request_uri",
+                "http_version",
+                "request_headers",
+                "request_trailers",
+                "request_body",
+            ]
+        } else {
+            ["method", "uri", "version", "headers", "trailers", "body"]
+        };
+
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry(method, &request.method)?;
+        map.serialize_entry(uri, &request.uri)?;
+        map.serialize_entry(version, &request.version)?;
+        map.serialize_entry(headers, &request.headers)?;
+        if !request.trailers.is_empty() {
+            map.serialize_entry(trailers, &request.trailers)?;
+        }
+        if self.options.include_chunk_lengths && !request.chunk_lengths.is_empty() {
+            map.serialize_entry("chunk_lengths", &request.chunk_lengths)?;
+        }
+        match &request.body {
+            None if self.options.skip_empty_body => {}
+            None => map.serialize_entry(body, &None::<&str>)?,
+            Some(bytes) => map.serialize_entry(body, &String::from_utf8_lossy(bytes))?,
+        }
+        if self.options.include_received_bytes {
+            map.serialize_entry("received_bytes", &request.received_bytes)?;
+        }
+        if self.options.include_post_terminator_data && request.post_terminator_data {
+            map.serialize_entry("post_terminator_data", &true)?;
+        }
+        map.end()
+    }
+}
+
 /// Render an [`HttpRequest`] in a human-readable debug format.
 pub fn format_debug(request: &HttpRequest) -> String {
     let mut out = String::with_capacity(256);
@@ -25,6 +117,22 @@ pub fn format_debug(request: &HttpRequest) -> String {
         out.push_str(&format!("  {}: {}\n", header.name, header.value));
     }
 
+    if !request.trailers.is_empty() {
+        out.push_str(&format!(
+            "\n--- Trailers ({}) ---\n",
+            request.trailers.len()
+        ));
+        for trailer in &request.trailers {
+            out.push_str(&format!("  {}: {}\n", trailer.name, trailer.value));
+        }
+    }
+
+    out.push_str("\n--- Framing ---\n");
+    out.push_str(&format!("  {}\n", request.framing()));
+    if request.has_framing_conflict() {
+        out.push_str("  (Content-Length ignored: Transfer-Encoding takes precedence)\n");
+    }
+
     match &request.body {
         Some(body) => {
             out.push_str(&format!("\n--- Body ({} bytes) ---\n", body.len()));
@@ -60,3 +168,143 @@ pub fn format_headers_only(request: &HttpRequest) -> String {
 
     out
 }
+
+/// Parse a request from `reader` and write it to `writer` as JSON while
+/// parsing progresses, without buffering the body.
+///
+/// The request line and headers are written as soon as the header section
+/// is complete; the decoded body follows as a base64 string under
+/// `"body_base64"`, encoded as it arrives through
+/// [`Parser::body_chunks`]. Memory use is bounded by the read buffer and the
+/// header section, whatever the body size. Trailer fields are not included.
+/// Bytes after the request are left unread in the last buffer.
+///
+/// ```rust
+/// let raw: &[u8] = b"POST / HTTP/1.1\r\nHost: h\r\nContent-Length: 5\r\n\r\nHello";
+/// let mut out = Vec::new();
+/// wireframe::stream_json(raw, &mut out, wireframe::ParserConfig::default()).unwrap();
+/// assert!(out.ends_with(br#""body_base64":"SGVsbG8="}"#));
+/// ```
+///
+/// # Errors
+///
+/// Returns the reader's or writer's I/O errors; a [`ParseError`] (including
+/// [`ParseError::IncompleteRequest`] when the reader ends early) is
+/// returned as an [`io::ErrorKind::InvalidData`] error wrapping it.
+pub fn stream_json<R: Read, W: Write>(
+    mut reader: R,
+    mut writer: W,
+    config: ParserConfig,
+) -> io::Result<()> {
+    let invalid = |e: ParseError| io::Error::new(io::ErrorKind::InvalidData, e);
+
+    let mut parser = Parser::with_config(config);
+    let mut buf = vec![0; 8 * 1024];
+    let mut body = Base64Encoder::default();
+    let mut head_written = false;
+
+    loop {
+        let n = reader.read(&mut buf)?;
+        if n == 0 {
+            return Err(invalid(ParseError::IncompleteRequest));
+        }
+
+        let mut chunks = parser.body_chunks(&buf[..n]);
+        while let Some(chunk) = chunks.next() {
+            let chunk = chunk.map_err(invalid)?;
+            if !head_written {
+                write_json_head(&mut writer, chunks.parser())?;
+                head_written = true;
+            }
+            body.write(&mut writer, chunk)?;
+        }
+
+        if !head_written && parser.head().is_some() {
+            write_json_head(&mut writer, &parser)?;
+            head_written = true;
+        }
+        if parser.is_complete() {
+            break;
+        }
+    }
+
+    body.finish(&mut writer)?;
+    writer.write_all(b"\"}")?;
+    writer.flush()
+}
+
+/// Write everything up to the opening quote of the body string.
+fn write_json_head<W: Write>(writer: &mut W, parser: &Parser) -> io::Result<()> {
+    let Some((method, uri, version, headers)) = parser.head() else {
+        return Ok(());
+    };
+    writer.write_all(b"{\"method\":")?;
+    serde_json::to_writer(&mut *writer, method)?;
+    writer.write_all(b",\"uri\":")?;
+    serde_json::to_writer(&mut *writer, uri)?;
+    writer.write_all(b",\"version\":")?;
+    serde_json::to_writer(&mut *writer, &version)?;
+    writer.write_all(b",\"headers\":")?;
+    serde_json::to_writer(&mut *writer, headers)?;
+    writer.write_all(b",\"body_base64\":\"")
+}
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Standard (padded) base64 over input that arrives in pieces.
+#[derive(Default)]
+struct Base64Encoder {
+    pending: [u8; 3],
+    pending_len: usize,
+}
+
+impl Base64Encoder {
+    fn write<W: Write>(&mut self, writer: &mut W, mut data: &[u8]) -> io::Result<()> {
+        // Complete a group left over from the previous piece.
+        while self.pending_len > 0 && self.pending_len < 3 {
+            let Some((&b, rest)) = data.split_first() else {
+                return Ok(());
+            };
+            self.pending[self.pending_len] = b;
+            self.pending_len += 1;
+            data = rest;
+        }
+        if self.pending_len == 3 {
+            writer.write_all(&encode_group(self.pending, 3))?;
+            self.pending_len = 0;
+        }
+
+        let groups = data.chunks_exact(3);
+        let tail = groups.remainder();
+        let mut out = Vec::with_capacity(data.len() / 3 * 4);
+        for group in groups {
+            out.extend_from_slice(&encode_group([group[0], group[1], group[2]], 3));
+        }
+        writer.write_all(&out)?;
+
+        self.pending[..tail.len()].copy_from_slice(tail);
+        self.pending_len = tail.len();
+        Ok(())
+    }
+
+    fn finish<W: Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        if self.pending_len > 0 {
+            let mut group = [0; 3];
+            group[..self.pending_len].copy_from_slice(&self.pending[..self.pending_len]);
+            writer.write_all(&encode_group(group, self.pending_len))?;
+            self.pending_len = 0;
+        }
+        Ok(())
+    }
+}
+
+/// Encode `len` (1..=3) bytes of `group` as four characters, padding with `=`.
+fn encode_group(group: [u8; 3], len: usize) -> [u8; 4] {
+    let n = u32::from(group[0]) << 16 | u32::from(group[1]) << 8 | u32::from(group[2]);
+    let mut out = [b'='; 4];
+    for (i, slot) in out.iter_mut().enumerate().take(len + 1) {
+        *slot = BASE64_ALPHABET[(n >> (18 - 6 * i) & 0x3F) as usize];
+    }
+    out
+}
@@ -0,0 +1,53 @@
+//! Reading one request from a `futures` [`AsyncRead`] source (with the
+//! `futures` feature).
+
+use std::future::poll_fn;
+use std::io::ErrorKind;
+use std::pin::Pin;
+
+use futures_io::AsyncRead;
+
+use crate::error::ParseError;
+use crate::parser::{ParseStatus, Parser, ParserConfig};
+use crate::types::HttpRequest;
+
+/// Largest single read, used while the rest of a body is of known length.
+const READ_CHUNK: usize = 8 * 1024;
+
+/// Read and parse exactly one HTTP request from an async `reader`.
+///
+/// Reads never go past the end of the request, so the next request (or
+/// anything else) is still unread in `reader` afterwards. To get there
+/// without a lookahead buffer, the request line, headers and chunk framing
+/// are read one byte at a time and only bodies of known remaining length
+/// in larger pieces; wrap unbuffered sockets in a `BufReader`. Pass
+/// `&mut reader` to keep using the reader.
+///
+/// # Errors
+///
+/// Returns [`ParseError::Io`] with the error kind if a read fails,
+/// [`ParseError::IncompleteRequest`] if the reader reaches EOF first (see
+/// [`ParserConfig::lenient_chunked_termination`]), or any error the parser
+/// reports for the data read.
+pub async fn parse_request_async<R: AsyncRead + Unpin>(
+    mut reader: R,
+    config: ParserConfig,
+) -> Result<HttpRequest, ParseError> {
+    let mut parser = Parser::with_config(config);
+    let mut buf = vec![0; READ_CHUNK];
+    loop {
+        let want = parser
+            .min_bytes_needed()
+            .map_or(1, |needed| needed.clamp(1, READ_CHUNK));
+        let n = match poll_fn(|cx| Pin::new(&mut reader).poll_read(cx, &mut buf[..want])).await {
+            // Let `finish` decide, so `lenient_chunked_termination` applies.
+            Ok(0) => return parser.finish(),
+            Ok(n) => n,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(ParseError::Io(e.kind())),
+        };
+        if let ParseStatus::Complete(_) = parser.feed(&buf[..n])? {
+            return parser.finish();
+        }
+    }
+}
@@ -0,0 +1,630 @@
+//! Allocation-free request boundary scanning.
+//!
+//! [`request_boundary`] walks the same grammar as [`Parser`](crate::Parser)
+//! but works directly on the caller's contiguous buffer: nothing is copied
+//! and no [`HttpRequest`](crate::HttpRequest) is built. It answers one
+//! question — "where does the first request end?" — for front ends that
+//! forward raw bytes.
+
+use std::ops::Range;
+
+use crate::borrowed::Spans;
+use crate::error::ParseError;
+use crate::parser::{
+    ParserConfig, check_header_name, check_request_target, header_section, is_field_content_byte,
+    is_tchar, parse_chunk_size, too_many_chunk_size_digits,
+};
+use crate::types::{Framing, HttpMethod, HttpVersion};
+
+/// Unwrap a `Result<Option<T>, ParseError>`, returning `Ok(None)` from the
+/// enclosing function when more data is needed.
+macro_rules! ready {
+    ($e:expr) => {
+        match $e? {
+            Some(v) => v,
+            None => return Ok(None),
+        }
+    };
+}
+
+/// Find the end of the first complete HTTP request in `data`.
+///
+/// Returns `Ok(Some(len))` when a full request (request line, headers and
+/// body) occupies `data[..len]`, or `Ok(None)` when more data is needed.
+/// Any bytes past `len` belong to the next request.
+///
+/// The request line, header syntax, framing headers (`Content-Length`,
+/// `Transfer-Encoding`) and the size limits in `config` are validated
+/// exactly as [`Parser`](crate::Parser) does; the strict/lenient options
+/// that only affect error classification are not applied.
+///
+/// # Errors
+///
+/// Returns [`ParseError`] if the bytes seen so far cannot start a valid
+/// request.
+pub fn request_boundary(data: &[u8], config: &ParserConfig) -> Result<Option<usize>, ParseError> {
+    resume_boundary(data, 0, config, &mut ScanProgress::default(), None)
+}
+
+/// How far a scan got through a request that was still incomplete, so the
+/// next scan can pick up there. Positions are relative to the scanned
+/// slice; see [`rebase`](Self::rebase).
+#[derive(Debug, Clone, Default)]
+pub(crate) enum ScanProgress {
+    /// Nothing validated yet.
+    #[default]
+    Start,
+    /// The request line is validated; the header lines before `pos` too.
+    Headers {
+        method: HttpMethod,
+        uri: Range<usize>,
+        version: HttpVersion,
+        headers_start: usize,
+        pos: usize,
+        count: usize,
+    },
+    /// The head is validated; the request ends at `end`.
+    Body { end: usize },
+    /// The head and the chunks before `pos` are validated; with
+    /// `after_data`, `pos` is the CRLF that ends a chunk's data.
+    Chunked {
+        pos: usize,
+        body_len: usize,
+        max_body_size: usize,
+        after_data: bool,
+    },
+}
+
+impl ScanProgress {
+    /// The first position the next scan still needs to see.
+    pub(crate) fn resume_at(&self) -> usize {
+        match *self {
+            Self::Start | Self::Headers { .. } => 0,
+            Self::Body { end } => end,
+            Self::Chunked { pos, .. } => pos,
+        }
+    }
+
+    /// Shift every position back by `by`, for a scan over the slice
+    /// starting `by` bytes later. `by` must not exceed
+    /// [`resume_at`](Self::resume_at).
+    pub(crate) fn rebase(&mut self, by: usize) {
+        match self {
+            Self::Start | Self::Headers { .. } => debug_assert_eq!(by, 0),
+            Self::Body { end } => *end -= by,
+            Self::Chunked { pos, .. } => *pos -= by,
+        }
+    }
+}
+
+/// [`request_boundary`] over `data`, which starts at offset `base` of the
+/// request, picking up where an earlier call left `progress`. With `spans`,
+/// the absolute positions of the request's fields are recorded there.
+pub(crate) fn resume_boundary(
+    data: &[u8],
+    base: usize,
+    config: &ParserConfig,
+    progress: &mut ScanProgress,
+    spans: Option<&mut Option<Spans>>,
+) -> Result<Option<usize>, ParseError> {
+    let boundary = Scanner {
+        data,
+        pos: 0,
+        base,
+        config,
+        spans,
+    }
+    .request(progress)?;
+    // Past the cap before the request ended, the parser would have failed.
+    let seen = base + boundary.unwrap_or(data.len());
+    if config.max_total_request_size.is_some_and(|max| seen > max) {
+        return Err(ParseError::RequestTooLarge);
+    }
+    Ok(boundary)
+}
+
+struct Scanner<'a> {
+    data: &'a [u8],
+    pos: usize,
+    /// Offset of `data` within the request.
+    base: usize,
+    config: &'a ParserConfig,
+    spans: Option<&'a mut Option<Spans>>,
+}
+
+impl<'a> Scanner<'a> {
+    fn request(&mut self, progress: &mut ScanProgress) -> Result<Option<usize>, ParseError> {
+        if let ScanProgress::Start = progress {
+            let (method, uri, version) = ready!(self.request_line());
+            *progress = ScanProgress::Headers {
+                method,
+                uri,
+                version,
+                headers_start: self.pos,
+                pos: self.pos,
+                count: 0,
+            };
+        }
+
+        // ---- Header section ----
+        if let ScanProgress::Headers {
+            method,
+            uri,
+            version,
+            headers_start,
+            pos,
+            count,
+        } = progress
+        {
+            self.pos = *pos;
+            loop {
+                match self.data.get(self.pos) {
+                    None => return Ok(None),
+                    Some(b'\r') => {
+                        self.pos += 1;
+                        ready!(self.lf("LF after end-of-headers CR"));
+                        break;
+                    }
+                    Some(&b) if is_tchar(b) => {
+                        if *count >= self.config.max_headers_count {
+                            return Err(ParseError::TooManyHeaders);
+                        }
+                        ready!(self.header_line());
+                        *count += 1;
+                        *pos = self.pos;
+                    }
+                    Some(b':') => return Err(ParseError::EmptyHeaderName),
+                    Some(&found) => {
+                        return Err(ParseError::UnexpectedByte {
+                            expected: "header name character or CR",
+                            found,
+                        });
+                    }
+                }
+            }
+
+            // Re-walk the (already validated) header lines for framing.
+            let fields = HeaderLines {
+                rest: &self.data[*headers_start..self.pos],
+            };
+            let uri = &self.data[uri.clone()];
+            let framing = header_section(fields.clone(), method, uri, *version, self.config)?;
+            let max_body_size = self.config.max_body_size_for(method);
+            if self.spans.is_some() {
+                let spans = Spans {
+                    method: *method,
+                    uri: self.span(uri),
+                    version: *version,
+                    headers: fields
+                        .map(|(name, value)| (self.span(name), self.span(value)))
+                        .collect(),
+                    trailers: Vec::new(),
+                    body: match framing {
+                        Framing::ContentLength(length) if length > 0 => {
+                            let start = self.base + self.pos;
+                            std::iter::once(start..start + length).collect()
+                        }
+                        _ => Vec::new(),
+                    },
+                    chunk_lengths: Vec::new(),
+                    len: 0,
+                };
+                if let Some(slot) = self.spans.as_deref_mut() {
+                    *slot = Some(spans);
+                }
+            }
+            *progress = match framing {
+                Framing::None => ScanProgress::Body { end: self.pos },
+                Framing::ContentLength(length) => ScanProgress::Body {
+                    end: self.pos + length,
+                },
+                Framing::Chunked => ScanProgress::Chunked {
+                    pos: self.pos,
+                    body_len: 0,
+                    max_body_size,
+                    after_data: false,
+                },
+            };
+        }
+
+        // ---- Body ----
+        match progress {
+            ScanProgress::Body { end } => Ok((*end <= self.data.len()).then_some(*end)),
+            ScanProgress::Chunked {
+                pos,
+                body_len,
+                max_body_size,
+                after_data,
+            } => {
+                self.pos = *pos;
+                self.chunked_body(pos, body_len, after_data, *max_body_size)
+            }
+            ScanProgress::Start | ScanProgress::Headers { .. } => {
+                unreachable!("advanced past by the steps above")
+            }
+        }
+    }
+
+    /// Scan the request line, returning the method, the range of the
+    /// request target and the version.
+    fn request_line(
+        &mut self,
+    ) -> Result<Option<(HttpMethod, Range<usize>, HttpVersion)>, ParseError> {
+        if self.config.allow_leading_crlf {
+            while self.data.get(self.pos) == Some(&b'\r') {
+                self.pos += 1;
+                ready!(self.lf("LF after leading CR"));
+            }
+        }
+
+        // ---- Request line ----
+        let method = ready!(self.token(
+            b' ',
+            self.config.max_method_len,
+            is_tchar,
+            "token character or SP in request method",
+            |_| ParseError::InvalidMethod("method too long".into()),
+        ));
+        let method = HttpMethod::from_bytes_with_config(method, self.config)?;
+
+        let config = self.config;
+        let uri = ready!(self.token(
+            b' ',
+            self.config.max_uri_len,
+            |b| { config.uri_validation.allows(b) && !(config.strict_request_line && b == b'\t') },
+            "visible character or SP in request URI",
+            |_| ParseError::InvalidUri("URI too long".into()),
+        ));
+        if uri.is_empty() {
+            return Err(ParseError::InvalidUri("empty URI".into()));
+        }
+        check_request_target(uri, self.config)?;
+        let uri_end = self.pos - 1;
+        let uri = uri_end - uri.len()..uri_end;
+
+        let version = ready!(self.token(
+            b'\r',
+            self.config.max_version_len,
+            |b| b >= b' ' && b != 0x7F,
+            "version character or CR",
+            |_| ParseError::InvalidVersion("version string too long".into()),
+        ));
+        let version = HttpVersion::from_bytes(version)?;
+        ready!(self.lf("LF after version CR"));
+        Ok(Some((method, uri, version)))
+    }
+
+    /// Scan one `name: value CRLF` header line (the first name byte is
+    /// known to be a `tchar`).
+    fn header_line(&mut self) -> Result<Option<()>, ParseError> {
+        let start = self.pos;
+        let name = match self.token(
+            b':',
+            self.config.max_header_name_len,
+            is_tchar,
+            "header name character or ':'",
+            |partial| ParseError::HeaderNameTooLarge(String::from_utf8_lossy(partial).into_owned()),
+        ) {
+            Err(ParseError::UnexpectedByte {
+                found: b' ' | b'\t',
+                ..
+            }) => {
+                let name = &self.data[start..self.pos];
+                if self.config.reject_space_before_colon {
+                    return Err(ParseError::WhitespaceBeforeColon(
+                        String::from_utf8_lossy(name).into_owned(),
+                    ));
+                }
+                while matches!(self.data.get(self.pos), Some(b' ' | b'\t')) {
+                    self.pos += 1;
+                }
+                match self.data.get(self.pos) {
+                    None => return Ok(None),
+                    Some(b':') => self.pos += 1,
+                    Some(&found) => {
+                        return Err(ParseError::UnexpectedByte {
+                            expected: "':' after header name",
+                            found,
+                        });
+                    }
+                }
+                name
+            }
+            other => ready!(other),
+        };
+
+        let ows_start = self.pos;
+        while matches!(self.data.get(self.pos), Some(b' ' | b'\t')) {
+            if self.pos - ows_start >= self.config.max_leading_ows {
+                return Err(ParseError::ExcessiveWhitespace(
+                    String::from_utf8_lossy(name).into_owned(),
+                ));
+            }
+            self.pos += 1;
+        }
+
+        let limit = std::str::from_utf8(name).map_or(self.config.max_header_value_len, |name| {
+            self.config.max_header_value_len_for(name)
+        });
+        ready!(
+            self.token(
+                b'\r',
+                limit,
+                is_field_content_byte,
+                "header value character or CR",
+                |_| ParseError::HeaderValueTooLarge(String::new()),
+            )
+            .map_err(|err| match err {
+                ParseError::HeaderValueTooLarge(_) => {
+                    ParseError::HeaderValueTooLarge(String::from_utf8_lossy(name).into_owned())
+                }
+                other => other,
+            })
+        );
+        check_header_name(name, self.config)?;
+        match self.data.get(self.pos) {
+            None => return Ok(None),
+            Some(b'\n') => self.pos += 1,
+            Some(_) => return Err(ParseError::BareCr),
+        }
+        Ok(Some(()))
+    }
+
+    /// Scan chunks from `self.pos`, advancing `next` and `body_len` past
+    /// each chunk-size line and each chunk's data.
+    fn chunked_body(
+        &mut self,
+        next: &mut usize,
+        body_len: &mut usize,
+        after_data: &mut bool,
+        max_body_size: usize,
+    ) -> Result<Option<usize>, ParseError> {
+        loop {
+            if *after_data {
+                match self.data.get(self.pos) {
+                    None => return Ok(None),
+                    Some(b'\r') => self.pos += 1,
+                    Some(&found) => {
+                        return Err(ParseError::UnexpectedByte {
+                            expected: "CR after chunk data",
+                            found,
+                        });
+                    }
+                }
+                ready!(self.lf("LF after chunk data CR"));
+                *next = self.pos;
+                *after_data = false;
+            }
+
+            let start = self.pos;
+            let delim = loop {
+                match self.data.get(self.pos) {
+                    None => return Ok(None),
+                    Some(&b) if b.is_ascii_hexdigit() => {
+                        if self.pos - start >= self.config.max_chunk_size_digits {
+                            return Err(too_many_chunk_size_digits(&self.data[start..self.pos]));
+                        }
+                        self.pos += 1;
+                    }
+                    Some(&b @ (b'\r' | b';')) => break b,
+                    Some(&found) => {
+                        return Err(ParseError::UnexpectedByte {
+                            expected: "hex digit, ';', or CR in chunk size",
+                            found,
+                        });
+                    }
+                }
+            };
+            let size = parse_chunk_size(&self.data[start..self.pos], self.config)?;
+            let total = body_len
+                .checked_add(size)
+                .filter(|&total| total <= max_body_size)
+                .ok_or(ParseError::BodyTooLarge)?;
+
+            if delim == b';' {
+                // RFC 9112 §7.1.1: ignore chunk extensions. `pos` is still
+                // at the ';', which counts towards the length.
+                let max = self.config.max_chunk_ext_len;
+                let ext = &self.data[self.pos..];
+                match ext.iter().position(|&b| b == b'\r') {
+                    Some(cr) if cr <= max => self.pos += cr,
+                    None if ext.len() <= max => return Ok(None),
+                    _ => return Err(ParseError::ChunkExtensionTooLarge),
+                }
+            }
+            self.pos += 1;
+            ready!(self.lf("LF after chunk size CR"));
+
+            if size == 0 {
+                return self.trailer_section();
+            }
+
+            let data = self.base + self.pos..self.base + self.pos + size;
+            if let Some(Some(spans)) = self.spans.as_deref_mut() {
+                if self.config.record_chunk_boundaries {
+                    spans.chunk_lengths.push(size);
+                }
+                spans.body.push(data);
+            }
+            self.pos += size;
+            *next = self.pos;
+            *body_len = total;
+            *after_data = true;
+        }
+    }
+
+    fn trailer_section(&mut self) -> Result<Option<usize>, ParseError> {
+        let start = self.pos;
+        // A resumed scan walks the whole section again.
+        if let Some(Some(spans)) = self.spans.as_deref_mut() {
+            spans.trailers.clear();
+        }
+        loop {
+            let first = ready!(self.trailer_byte(start));
+            self.pos += 1;
+            match first {
+                b'\r' => {
+                    // The final LF does not count towards the limit.
+                    ready!(self.lf("LF after trailer-section end CR"));
+                    return Ok(Some(self.pos));
+                }
+                b':' => return Err(ParseError::EmptyHeaderName),
+                b if is_tchar(b) => {}
+                found => {
+                    return Err(ParseError::UnexpectedByte {
+                        expected: "trailer name character or CR",
+                        found,
+                    });
+                }
+            }
+
+            // Trailer fields follow the header-field grammar.
+            let name_start = self.pos - 1;
+            let mut name_end = None;
+            loop {
+                let b = ready!(self.trailer_byte(start));
+                match b {
+                    b':' => {
+                        name_end.get_or_insert(self.pos);
+                        self.pos += 1;
+                        break;
+                    }
+                    b if is_tchar(b) && name_end.is_none() => {}
+                    b' ' | b'\t' => {
+                        if self.config.reject_space_before_colon {
+                            return Err(ParseError::WhitespaceBeforeColon(
+                                String::from_utf8_lossy(&self.data[name_start..self.pos])
+                                    .into_owned(),
+                            ));
+                        }
+                        name_end.get_or_insert(self.pos);
+                    }
+                    found => {
+                        return Err(ParseError::UnexpectedByte {
+                            expected: if name_end.is_some() {
+                                "':' after trailer name"
+                            } else {
+                                "trailer name character or ':'"
+                            },
+                            found,
+                        });
+                    }
+                }
+                self.pos += 1;
+            }
+            let value_start = self.pos;
+            loop {
+                let b = ready!(self.trailer_byte(start));
+                self.pos += 1;
+                if b == b'\r' {
+                    break;
+                }
+                if !is_field_content_byte(b) {
+                    return Err(ParseError::UnexpectedByte {
+                        expected: "trailer value character or CR",
+                        found: b,
+                    });
+                }
+            }
+            let lf = ready!(self.trailer_byte(start));
+            if lf != b'\n' {
+                return Err(ParseError::UnexpectedByte {
+                    expected: "LF after trailer field CR",
+                    found: lf,
+                });
+            }
+            if self.spans.is_some() {
+                let name = self.span(&self.data[name_start..name_end.unwrap_or(name_start)]);
+                let value = self.span(self.data[value_start..self.pos - 1].trim_ascii());
+                if let Some(Some(spans)) = self.spans.as_deref_mut() {
+                    spans.trailers.push((name, value));
+                }
+            }
+            self.pos += 1;
+        }
+    }
+
+    /// The position of `part`, a slice of `data`, within the request.
+    fn span(&self, part: &[u8]) -> Range<usize> {
+        let start = self.base + (part.as_ptr() as usize - self.data.as_ptr() as usize);
+        start..start + part.len()
+    }
+
+    /// The next trailer-section byte, counted against `max_trailer_bytes`
+    /// from `start`.
+    fn trailer_byte(&self, start: usize) -> Result<Option<u8>, ParseError> {
+        match self.data.get(self.pos) {
+            None => Ok(None),
+            Some(_) if self.pos - start >= self.config.max_trailer_bytes => {
+                Err(ParseError::TrailerTooLarge)
+            }
+            Some(&b) => Ok(Some(b)),
+        }
+    }
+
+    // ----- primitives -----------------------------------------------------
+
+    /// Consume bytes up to (and including) `delim`, returning the bytes
+    /// before it. Each byte must satisfy `valid`; more than `max` of them
+    /// yields `too_long` applied to the bytes consumed so far.
+    fn token(
+        &mut self,
+        delim: u8,
+        max: usize,
+        valid: impl Fn(u8) -> bool,
+        expected: &'static str,
+        too_long: fn(&[u8]) -> ParseError,
+    ) -> Result<Option<&'a [u8]>, ParseError> {
+        let start = self.pos;
+        while let Some(&b) = self.data.get(self.pos) {
+            if b == delim {
+                self.pos += 1;
+                return Ok(Some(&self.data[start..self.pos - 1]));
+            }
+            if !valid(b) {
+                return Err(ParseError::UnexpectedByte { expected, found: b });
+            }
+            if self.pos - start >= max {
+                return Err(too_long(&self.data[start..self.pos]));
+            }
+            self.pos += 1;
+        }
+        Ok(None)
+    }
+
+    /// Consume the LF that must follow a CR.
+    fn lf(&mut self, expected: &'static str) -> Result<Option<()>, ParseError> {
+        match self.data.get(self.pos) {
+            None => Ok(None),
+            Some(b'\n') => {
+                self.pos += 1;
+                Ok(Some(()))
+            }
+            Some(&found) => Err(ParseError::UnexpectedByte { expected, found }),
+        }
+    }
+}
+
+/// Iterator over `(name, value)` pairs of an already-validated header
+/// section (without the terminating empty line).
+#[derive(Clone)]
+pub(crate) struct HeaderLines<'a> {
+    pub(crate) rest: &'a [u8],
+}
+
+impl<'a> Iterator for HeaderLines<'a> {
+    type Item = (&'a [u8], &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let end = self.rest.iter().position(|&b| b == b'\r')?;
+        let line = &self.rest[..end];
+        self.rest = self.rest.get(end + 2..).unwrap_or_default();
+        let colon = line.iter().position(|&b| b == b':')?;
+        // The name only ends in whitespace when `reject_space_before_colon`
+        // is off.
+        Some((
+            line[..colon].trim_ascii_end(),
+            line[colon + 1..].trim_ascii(),
+        ))
+    }
+}
@@ -0,0 +1,18 @@
+//! Optional `tracing` instrumentation.
+//!
+//! With the `tracing` feature disabled these macros expand to nothing, so
+//! the parser carries no instrumentation cost.
+
+#[cfg(feature = "tracing")]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        ::tracing::debug!($($arg)*)
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! debug {
+    ($($arg:tt)*) => {};
+}
+
+pub(crate) use debug;
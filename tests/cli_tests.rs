@@ -0,0 +1,124 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+/// Run the CLI binary with `args`, piping `stdin` to it.
+fn run_cli(args: &[&str], stdin: &[u8]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_wireframe-cli"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("spawn wireframe-cli");
+    child
+        .stdin
+        .take()
+        .expect("stdin")
+        .write_all(stdin)
+        .expect("write stdin");
+    child.wait_with_output().expect("wait for wireframe-cli")
+}
+
+// =========================================================================
+// Trailing bytes
+// =========================================================================
+
+#[test]
+fn report_leftover_counts_trailing_bytes() {
+    let out = run_cli(&["--report-leftover"], b"GET / HTTP/1.1\r\n\r\njunk");
+    assert!(out.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&out.stderr),
+        "4 trailing byte(s) after the request\n"
+    );
+    assert!(String::from_utf8_lossy(&out.stdout).contains("\"uri\":\"/\""));
+}
+
+#[test]
+fn require_exact_rejects_trailing_bytes() {
+    let out = run_cli(&["--require-exact"], b"GET / HTTP/1.1\r\n\r\nGET /");
+    assert_eq!(out.status.code(), Some(3));
+    assert!(out.stdout.is_empty());
+
+    let out = run_cli(&["--require-exact"], b"GET / HTTP/1.1\r\n\r\n");
+    assert!(out.status.success());
+}
+
+// =========================================================================
+// Line-ending normalization
+// =========================================================================
+
+#[test]
+fn auto_crlf_converts_lf_only_headers() {
+    let path = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("lf_only.http");
+    std::fs::write(
+        &path,
+        "POST /lf HTTP/1.1\nHost: h\nContent-Length: 4\n\na\nb\n",
+    )
+    .expect("write LF-only request");
+    let file = path.to_str().expect("utf-8 path");
+
+    let out = run_cli(&[file], b"");
+    assert_eq!(out.status.code(), Some(2));
+
+    let out = run_cli(&["--auto-crlf", "--report-leftover", file], b"");
+    assert!(out.status.success());
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(stderr.contains("converted 4 LF line ending(s) to CRLF"));
+    // The body keeps its bare LFs and its length.
+    assert!(stderr.contains("0 trailing byte(s)"));
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(stdout.contains("\"uri\":\"/lf\""));
+    assert!(stdout.contains("\"body\":\"a\\nb\\n\""));
+}
+
+// =========================================================================
+// Benchmark mode
+// =========================================================================
+
+#[test]
+fn bench_prints_timing_stats() {
+    let out = run_cli(&["--bench", "3"], b"GET / HTTP/1.1\r\nHost: h\r\n\r\n");
+    assert!(out.status.success());
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(stdout.starts_with("iterations: 3\n"));
+    for stat in ["min:", "median:", "max:", "mean:", "throughput:"] {
+        assert!(stdout.contains(stat), "missing {stat} in {stdout}");
+    }
+    assert!(!stdout.contains("\"uri\""));
+
+    let out = run_cli(&["--bench", "3"], b"GET / HTTP/1.1\r\n");
+    assert_eq!(out.status.code(), Some(2));
+}
+
+#[test]
+fn trace_prints_state_per_byte() {
+    let out = run_cli(&["--trace"], b"GET / HTTP/1.1\r\nHost: h\r\n\r\n");
+    assert!(out.status.success());
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    let mut lines = stdout.lines();
+    assert_eq!(lines.next(), Some("offset  byte  state"));
+    assert_eq!(lines.next(), Some("     0  G     Method"));
+    assert!(stdout.contains("     3  SP    Method\n"));
+    assert!(stdout.contains("    15  LF    VersionLf\n"));
+
+    // A malformed request is traced up to the offending byte.
+    let out = run_cli(&["--trace"], b"GET / HTTP/1.1\r\nHost : h\r\n\r\n");
+    assert_eq!(out.status.code(), Some(2));
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(stdout.ends_with("    20  SP    HeaderName\n"), "{stdout}");
+}
+
+#[test]
+fn tolerant_accepts_bare_lf_and_extension_methods() {
+    let input = b"\r\nPROPFIND /dav HTTP/1.1\nHost: h\n\n";
+
+    let out = run_cli(&[], input);
+    assert_eq!(out.status.code(), Some(2));
+
+    let out = run_cli(&["--tolerant"], input);
+    assert!(out.status.success());
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(stdout.contains("\"method\":\"PROPFIND\""));
+    assert!(stdout.contains("\"uri\":\"/dav\""));
+}
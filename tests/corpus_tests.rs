@@ -0,0 +1,113 @@
+//! Regression corpus: raw requests (mostly from fuzzing) with a sidecar
+//! expectation each.
+//!
+//! Every `tests/corpus/<name>.http` file is fed to [`Parser::feed`] under a
+//! number of split strategies and must either parse to the same
+//! [`HttpRequest`] every time or fail with the same error variant every
+//! time. `<name>.expect` holds the expected outcome on its first line:
+//!
+//! - `ok` — the request parses;
+//! - `error <Variant>` — parsing fails with `ParseError::<Variant>` (the
+//!   payload, if any, is not compared).
+//!
+//! To add a case, drop the two files into `tests/corpus/`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use wireframe::{HttpRequest, ParseError, ParseStatus, Parser};
+
+#[derive(Debug, PartialEq)]
+enum Expectation {
+    Ok,
+    Error(String),
+}
+
+/// Feed `chunks` to a fresh parser in order, stopping at the first
+/// `Complete`, and finish it.
+fn run<'a>(chunks: impl IntoIterator<Item = &'a [u8]>) -> Result<HttpRequest, ParseError> {
+    let mut parser = Parser::new();
+    for chunk in chunks {
+        if let ParseStatus::Complete(_) = parser.feed(chunk)? {
+            break;
+        }
+    }
+    parser.finish()
+}
+
+/// The ways each corpus input is cut into reads, by name.
+fn split_strategies(data: &[u8]) -> Vec<(String, Result<HttpRequest, ParseError>)> {
+    let mut outcomes = vec![
+        ("whole".to_string(), run([data])),
+        ("byte-by-byte".to_string(), run(data.chunks(1))),
+    ];
+    for size in [2, 3, 7, 64] {
+        outcomes.push((format!("chunks of {size}"), run(data.chunks(size))));
+    }
+    // Every two-way split, so each boundary is hit once.
+    for at in 1..data.len() {
+        let (head, tail) = data.split_at(at);
+        outcomes.push((format!("split at {at}"), run([head, tail])));
+    }
+    outcomes
+}
+
+/// The `ParseError` variant name, without its payload.
+fn variant(err: &ParseError) -> String {
+    let debug = format!("{err:?}");
+    let end = debug.find(['(', ' ', '{']).unwrap_or(debug.len());
+    debug[..end].to_string()
+}
+
+fn read_expectation(path: &Path) -> Expectation {
+    let text = fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("{}: missing expectation file ({e})", path.display()));
+    let line = text.lines().next().unwrap_or("").trim();
+    match line.split_once(' ') {
+        None if line == "ok" => Expectation::Ok,
+        Some(("error", name)) => Expectation::Error(name.trim().to_string()),
+        _ => panic!(
+            "{}: expected `ok` or `error <Variant>`, got {line:?}",
+            path.display()
+        ),
+    }
+}
+
+fn corpus_files() -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/corpus");
+    let mut files: Vec<PathBuf> = fs::read_dir(&dir)
+        .unwrap_or_else(|e| panic!("{}: {e}", dir.display()))
+        .map(|entry| entry.expect("corpus entry").path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "http"))
+        .collect();
+    files.sort();
+    files
+}
+
+#[test]
+fn corpus_outcomes_are_stable_across_splits() {
+    let files = corpus_files();
+    assert!(!files.is_empty(), "no corpus files found");
+
+    for path in files {
+        let data = fs::read(&path).expect("read corpus file");
+        let expected = read_expectation(&path.with_extension("expect"));
+        let outcomes = split_strategies(&data);
+        let (_, reference) = &outcomes[0];
+
+        for (strategy, outcome) in &outcomes {
+            let name = path.display();
+            match (&expected, outcome) {
+                (Expectation::Ok, Ok(req)) => {
+                    assert_eq!(Some(req), reference.as_ref().ok(), "{name}: {strategy}");
+                }
+                (Expectation::Error(want), Err(err)) => {
+                    assert_eq!(&variant(err), want, "{name}: {strategy}: {err}");
+                }
+                (_, outcome) => {
+                    panic!("{name}: {strategy}: expected {expected:?}, got {outcome:?}")
+                }
+            }
+        }
+    }
+}